@@ -0,0 +1,208 @@
+//! Support for custom allocators via the (nightly-only) `allocator_api` feature
+
+use crate::{EmptyError, NonEmtpyVec};
+use std::alloc::Allocator;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem::ManuallyDrop;
+use std::num::NonZeroUsize;
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use std::slice;
+use std::vec;
+
+impl<T, A: Allocator> Deref for NonEmtpyVec<T, A> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.inner[..]
+    }
+}
+
+impl<T, A: Allocator> DerefMut for NonEmtpyVec<T, A> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.inner[..]
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for NonEmtpyVec<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NonEmtpyVec")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for NonEmtpyVec<T, A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, A: Allocator> PartialEq for NonEmtpyVec<T, A> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for NonEmtpyVec<T, A> {}
+
+impl<T: PartialOrd, A: Allocator> PartialOrd for NonEmtpyVec<T, A> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.inner.partial_cmp(&other.inner)
+    }
+}
+
+impl<T: Ord, A: Allocator> Ord for NonEmtpyVec<T, A> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl<T: Hash, A: Allocator> Hash for NonEmtpyVec<T, A> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state)
+    }
+}
+
+impl<T, A: Allocator> NonEmtpyVec<T, A> {
+    /// Constructs a new `NonEmptyVec<T, A>` from a single element, using the given allocator
+    #[inline]
+    pub fn new_in(val: T, alloc: A) -> Self {
+        let mut inner = Vec::with_capacity_in(1, alloc);
+        inner.push(val);
+        Self { inner }
+    }
+
+    /// Like [`with_first_and_capacity`](struct.NonEmtpyVec.html#method.with_first_and_capacity), but using the given allocator
+    #[inline]
+    pub fn with_first_and_capacity_in(first: T, capacity: NonZeroUsize, alloc: A) -> Self {
+        let mut inner = Vec::with_capacity_in(capacity.get(), alloc);
+        inner.push(first);
+        Self { inner }
+    }
+
+    /// Works like [`from_raw_parts_in`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.from_raw_parts_in)
+    ///
+    /// # Safety
+    /// The same constraints as for [`from_raw_parts_in`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.from_raw_parts_in) apply
+    #[inline]
+    pub unsafe fn from_raw_parts_in(
+        ptr: *mut T,
+        length: NonZeroUsize,
+        capacity: NonZeroUsize,
+        alloc: A,
+    ) -> Self {
+        Self {
+            inner: Vec::from_raw_parts_in(ptr, length.get(), capacity.get(), alloc),
+        }
+    }
+
+    /// Decomposes `self` into its raw components `(pointer, length, capacity, allocator)`
+    ///
+    /// The memory can be reclaimed via [`from_raw_parts_in`](#method.from_raw_parts_in)
+    #[inline]
+    pub fn into_raw_parts_with_alloc(self) -> (*mut T, NonZeroUsize, NonZeroUsize, A) {
+        let mut inner = ManuallyDrop::new(self.inner);
+        let len = inner.len();
+        let cap = inner.capacity();
+        let ptr = inner.as_mut_ptr();
+        // this is ok since `inner` is never dropped or used again
+        let alloc = unsafe { ptr::read(inner.allocator()) };
+        // this is ok since it relies on the safety guarantee that there is always at least one element
+        unsafe {
+            (
+                ptr,
+                NonZeroUsize::new_unchecked(len),
+                NonZeroUsize::new_unchecked(cap),
+                alloc,
+            )
+        }
+    }
+
+    /// Returns a reference to the underlying allocator
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        self.inner.allocator()
+    }
+}
+
+impl<T, A: Allocator + Clone> NonEmtpyVec<T, A> {
+    #[inline]
+    pub fn split_off(&mut self, at: NonZeroUsize) -> Vec<T, A> {
+        self.inner.split_off(at.get())
+    }
+}
+
+impl<'a, T: 'a + Copy, A: Allocator> Extend<&'a T> for NonEmtpyVec<T, A> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for NonEmtpyVec<T, A> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for NonEmtpyVec<T, A> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T, A>;
+
+    #[inline]
+    fn into_iter(self) -> vec::IntoIter<T, A> {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a NonEmtpyVec<T, A> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.inner.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut NonEmtpyVec<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.inner.iter_mut()
+    }
+}
+
+impl<T, A: Allocator> From<NonEmtpyVec<T, A>> for Vec<T, A> {
+    #[inline]
+    fn from(v: NonEmtpyVec<T, A>) -> Self {
+        v.inner
+    }
+}
+
+impl<T, A: Allocator> TryFrom<Vec<T, A>> for NonEmtpyVec<T, A> {
+    type Error = EmptyError<Vec<T, A>>;
+
+    #[inline]
+    fn try_from(v: Vec<T, A>) -> Result<Self, Self::Error> {
+        if v.is_empty() {
+            Err(EmptyError(v))
+        } else {
+            Ok(Self { inner: v })
+        }
+    }
+}
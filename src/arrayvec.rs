@@ -0,0 +1,34 @@
+//! Conversions from and to [`ArrayVec`](https://docs.rs/arrayvec/0.7/arrayvec/struct.ArrayVec.html) via the `arrayvec` feature
+
+use crate::{EmptyError, NonEmtpyVec};
+use ::arrayvec::{ArrayVec, CapacityError};
+use std::convert::TryFrom;
+
+impl<T, const N: usize> TryFrom<ArrayVec<T, N>> for NonEmtpyVec<T> {
+    type Error = EmptyError<ArrayVec<T, N>>;
+
+    #[inline]
+    fn try_from(v: ArrayVec<T, N>) -> Result<Self, Self::Error> {
+        if v.is_empty() {
+            Err(EmptyError(v))
+        } else {
+            Ok(Self {
+                inner: v.into_iter().collect(),
+            })
+        }
+    }
+}
+
+impl<T> NonEmtpyVec<T> {
+    /// Moves the elements into an `ArrayVec<T, N>`
+    ///
+    /// Fails (returning `self` inside the error) if `self.len() > N`
+    #[inline]
+    pub fn try_into_arrayvec<const N: usize>(self) -> Result<ArrayVec<T, N>, CapacityError<Self>> {
+        if self.len() > N {
+            Err(CapacityError::new(self))
+        } else {
+            Ok(self.inner.into_iter().collect())
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::NonEmptySlice;
+use std::iter::FusedIterator;
+use std::slice;
+
+macro_rules! chunks_iter {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Debug)]
+        pub struct $name<'a, T> {
+            inner: slice::$name<'a, T>,
+        }
+
+        impl<'a, T> $name<'a, T> {
+            pub(crate) fn new(inner: slice::$name<'a, T>) -> Self {
+                Self { inner }
+            }
+        }
+
+        impl<T> Clone for $name<'_, T> {
+            fn clone(&self) -> Self {
+                Self {
+                    inner: self.inner.clone(),
+                }
+            }
+        }
+
+        impl<'a, T> Iterator for $name<'a, T> {
+            type Item = &'a NonEmptySlice<T>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                // this is ok since the chunk size is never 0
+                self.inner
+                    .next()
+                    .map(|c| unsafe { NonEmptySlice::from_slice_unchecked(c) })
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                // this is ok since the chunk size is never 0
+                self.inner
+                    .nth(n)
+                    .map(|c| unsafe { NonEmptySlice::from_slice_unchecked(c) })
+            }
+        }
+
+        impl<T> DoubleEndedIterator for $name<'_, T> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                // this is ok since the chunk size is never 0
+                self.inner
+                    .next_back()
+                    .map(|c| unsafe { NonEmptySlice::from_slice_unchecked(c) })
+            }
+        }
+
+        impl<T> ExactSizeIterator for $name<'_, T> {}
+
+        impl<T> FusedIterator for $name<'_, T> {}
+    };
+}
+
+chunks_iter! {
+    /// An iterator over non-overlapping chunks of a `NonEmptySlice<T>`, starting at the front
+    ///
+    /// This is returned by [`NonEmptySlice::chunks`](struct.NonEmptySlice.html#method.chunks)
+    /// and [`NonEmptyVec::chunks`](struct.NonEmtpyVec.html#method.chunks).
+    /// Like [`slice::Chunks`](https://doc.rust-lang.org/std/slice/struct.Chunks.html), but yields `NonEmptySlice`s.
+    /// It always yields at least one chunk
+    Chunks
+}
+
+chunks_iter! {
+    /// An iterator over non-overlapping chunks of a `NonEmptySlice<T>`, starting at the back
+    ///
+    /// This is returned by [`NonEmptySlice::rchunks`](struct.NonEmptySlice.html#method.rchunks)
+    /// and [`NonEmptyVec::rchunks`](struct.NonEmtpyVec.html#method.rchunks).
+    /// Like [`slice::RChunks`](https://doc.rust-lang.org/std/slice/struct.RChunks.html), but yields `NonEmptySlice`s.
+    /// It always yields at least one chunk
+    RChunks
+}
@@ -0,0 +1,121 @@
+use crate::NonEmtpyVec;
+use std::iter::FusedIterator;
+
+/// An iterator over all orderings of the elements of a `NonEmptySlice<T>`
+///
+/// This is returned by [`NonEmptySlice::permutations`](struct.NonEmptySlice.html#method.permutations)
+/// and [`NonEmptyVec::permutations`](struct.NonEmtpyVec.html#method.permutations).
+/// The permutations are yielded in lexicographic order of the elements' positions,
+/// starting with the original order. It always yields at least one permutation
+#[derive(Debug)]
+pub struct Permutations<'a, T> {
+    slice: &'a [T],
+    /// The positions of the next permutation, or `None` if all have been yielded
+    indices: Option<Vec<usize>>,
+}
+
+impl<'a, T> Permutations<'a, T> {
+    pub(crate) fn new(slice: &'a [T]) -> Self {
+        Self {
+            slice,
+            indices: Some((0..slice.len()).collect()),
+        }
+    }
+}
+
+impl<T> Clone for Permutations<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            indices: self.indices.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Permutations<'a, T> {
+    type Item = NonEmtpyVec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let slice = self.slice;
+        let indices = self.indices.as_mut()?;
+        let item = NonEmtpyVec {
+            inner: indices.iter().map(|&i| &slice[i]).collect(),
+        };
+        let n = indices.len();
+        match (1..n).rev().find(|&i| indices[i - 1] < indices[i]) {
+            Some(i) => {
+                let j = match (i..n).rev().find(|&j| indices[i - 1] < indices[j]) {
+                    Some(j) => j,
+                    None => unreachable!(),
+                };
+                indices.swap(i - 1, j);
+                indices[i..].reverse();
+            }
+            None => self.indices = None,
+        }
+        Some(item)
+    }
+}
+
+impl<T> FusedIterator for Permutations<'_, T> {}
+
+/// An iterator over all selections of `k` elements of a `NonEmptySlice<T>`
+///
+/// This is returned by [`NonEmptySlice::combinations`](struct.NonEmptySlice.html#method.combinations)
+/// and [`NonEmptyVec::combinations`](struct.NonEmtpyVec.html#method.combinations).
+/// The selected elements keep their relative order and the combinations are yielded in lexicographic order of their positions.
+/// It yields nothing if `k` is greater than the length of the slice
+#[derive(Debug)]
+pub struct Combinations<'a, T> {
+    slice: &'a [T],
+    /// The positions of the next combination, or `None` if all have been yielded
+    indices: Option<Vec<usize>>,
+}
+
+impl<'a, T> Combinations<'a, T> {
+    /// The caller has to make sure that `k > 0`
+    pub(crate) fn new(slice: &'a [T], k: usize) -> Self {
+        Self {
+            slice,
+            indices: if k <= slice.len() {
+                Some((0..k).collect())
+            } else {
+                None
+            },
+        }
+    }
+}
+
+impl<T> Clone for Combinations<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            indices: self.indices.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Combinations<'a, T> {
+    type Item = NonEmtpyVec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let slice = self.slice;
+        let indices = self.indices.as_mut()?;
+        let item = NonEmtpyVec {
+            inner: indices.iter().map(|&i| &slice[i]).collect(),
+        };
+        let (n, k) = (slice.len(), indices.len());
+        match (0..k).rev().find(|&i| indices[i] != i + n - k) {
+            Some(i) => {
+                indices[i] += 1;
+                for j in i + 1..k {
+                    indices[j] = indices[j - 1] + 1;
+                }
+            }
+            None => self.indices = None,
+        }
+        Some(item)
+    }
+}
+
+impl<T> FusedIterator for Combinations<'_, T> {}
@@ -0,0 +1,189 @@
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::{fmt, ptr, slice};
+
+/// A draining iterator for `NonEmptyVec<T>`
+///
+/// This is returned by [`NonEmptyVec::drain`](struct.NonEmtpyVec.html#method.drain).
+/// The drained elements are moved behind the end of the `NonEmptyVec` when this is created,
+/// so even if this iterator is leaked, the `NonEmptyVec` is left in a valid (non-empty) state
+pub struct Drain<'a, T> {
+    vec: &'a mut Vec<T>,
+    /// The (absolute) index of the next element to yield from the front
+    idx: usize,
+    /// The (absolute) index after the next element to yield from the back
+    end: usize,
+}
+
+impl<'a, T> Drain<'a, T> {
+    /// The caller has to make sure that `range` is in bounds and doesn't cover all of `vec`
+    pub(crate) fn new(vec: &'a mut Vec<T>, range: Range<usize>) -> Self {
+        let len = vec.len();
+        vec[range.start..].rotate_left(range.end - range.start);
+        let new_len = len - (range.end - range.start);
+        // this is ok since the elements behind `new_len` are only ever read once (by us)
+        unsafe { vec.set_len(new_len) };
+        Self {
+            vec,
+            idx: new_len,
+            end: len,
+        }
+    }
+
+    /// Drops the remaining elements and returns the underlying `Vec`
+    pub(crate) fn finish(&mut self) -> &mut Vec<T> {
+        let remaining = ptr::slice_from_raw_parts_mut(
+            unsafe { self.vec.as_mut_ptr().add(self.idx) },
+            self.end - self.idx,
+        );
+        self.idx = self.end;
+        unsafe { ptr::drop_in_place(remaining) };
+        self.vec
+    }
+
+    /// Returns the remaining elements as a slice
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // this is ok since the elements in `idx..end` are still initialized
+        unsafe { slice::from_raw_parts(self.vec.as_ptr().add(self.idx), self.end - self.idx) }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Drain<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Drain").field(&self.as_slice()).finish()
+    }
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.idx == self.end {
+            None
+        } else {
+            self.idx += 1;
+            Some(unsafe { ptr::read(self.vec.as_ptr().add(self.idx - 1)) })
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.idx == self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(unsafe { ptr::read(self.vec.as_ptr().add(self.end)) })
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> FusedIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ne_vec, NonEmtpyVec};
+    use std::cell::Cell;
+    use std::mem;
+    use std::num::NonZeroUsize;
+    use std::rc::Rc;
+
+    #[test]
+    fn drain_range() {
+        let mut v = ne_vec![1, 2, 3, 4, 5];
+        let drained: Vec<_> = v.drain(1..4).unwrap().collect();
+        assert_eq!(drained, [2, 3, 4]);
+        assert_eq!(v, [1, 5]);
+    }
+
+    #[test]
+    fn drain_both_ends() {
+        let mut v = ne_vec![1, 2, 3, 4, 5];
+        let mut drain = v.drain(..4).unwrap();
+        assert_eq!(drain.next_back(), Some(4));
+        assert_eq!(drain.next(), Some(1));
+        assert_eq!(drain.as_slice(), [2, 3]);
+        assert_eq!(drain.len(), 2);
+        drop(drain);
+        assert_eq!(v, [5]);
+    }
+
+    #[test]
+    fn drain_everything() {
+        let mut v = ne_vec![1, 2, 3];
+        assert!(v.drain(..).is_err());
+        assert_eq!(v, [1, 2, 3]);
+    }
+
+    #[test]
+    fn drain_tail() {
+        let mut v = ne_vec![1, 2, 3];
+        assert_eq!(v.drain_tail().collect::<Vec<_>>(), [2, 3]);
+        assert_eq!(v, [1]);
+    }
+
+    #[test]
+    fn early_drop_drops_remaining() {
+        let rc = Rc::new(());
+        let mut v = NonEmtpyVec::from_elem(rc.clone(), NonZeroUsize::new(4).unwrap());
+        let mut drain = v.drain(1..).unwrap();
+        drop(drain.next());
+        drop(drain);
+        assert_eq!(v.len(), 1);
+        assert_eq!(Rc::strong_count(&rc), 2);
+    }
+
+    #[test]
+    fn leak() {
+        struct Counted<'a>(&'a Cell<usize>);
+
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+        let mut v = ne_vec![
+            Counted(&drops),
+            Counted(&drops),
+            Counted(&drops),
+            Counted(&drops)
+        ];
+        let mut drain = v.drain(..3).unwrap();
+        drop(drain.next());
+        mem::forget(drain);
+        assert_eq!(v.len(), 1);
+        // the two undrained elements are leaked, but not dropped
+        assert_eq!(drops.get(), 1);
+        v.push(Counted(&drops));
+        drop(v);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn zst() {
+        let mut v = ne_vec![(); 5];
+        assert_eq!(v.drain(1..3).unwrap().count(), 2);
+        assert_eq!(v.len(), 3);
+        mem::forget(v.drain(1..).unwrap());
+        assert_eq!(v.len(), 1);
+    }
+}
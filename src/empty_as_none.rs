@@ -0,0 +1,30 @@
+//! (De)serializes an `Option<NonEmptyVec<T>>` as a possibly empty sequence, via the `serde` feature
+//!
+//! Use it with `#[serde(with = "nonempty_vec::empty_as_none")]`.
+//! `None` is serialized as an empty sequence and an empty sequence is deserialized as `None`.
+//! To also allow the field to be left out, add `#[serde(default)]`
+
+use crate::NonEmtpyVec;
+use ::serde::de::{Deserialize, Deserializer};
+use ::serde::ser::{Serialize, Serializer};
+use std::iter;
+
+/// Serializes `v` as a sequence, which is empty if `v` is `None`
+#[inline]
+pub fn serialize<T: Serialize, S: Serializer>(
+    v: &Option<NonEmtpyVec<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match v {
+        Some(v) => v.serialize(serializer),
+        None => serializer.collect_seq(iter::empty::<T>()),
+    }
+}
+
+/// Deserializes a sequence, returning `None` if it is empty
+#[inline]
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<NonEmtpyVec<T>>, D::Error> {
+    Vec::deserialize(deserializer).map(NonEmtpyVec::from_vec)
+}
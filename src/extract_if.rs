@@ -0,0 +1,200 @@
+use std::ops::Range;
+use std::ptr;
+use std::slice;
+
+/// An iterator which uses a closure to determine if an element should be removed
+///
+/// This is returned by [`NonEmptyVec::extract_if`](struct.NonEmtpyVec.html#method.extract_if).
+/// Unlike its `Vec` counterpart, it never removes the last remaining element,
+/// and the `NonEmptyVec` stays non-empty even if this iterator is leaked
+pub struct ExtractIf<'a, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    vec: &'a mut Vec<T>,
+    /// The index of the next element to check
+    idx: usize,
+    /// The end of the range to check
+    end: usize,
+    /// The number of elements removed so far (these form a hole in front of `idx`)
+    del: usize,
+    /// The length of `vec` before any elements were removed
+    old_len: usize,
+    pred: F,
+}
+
+impl<'a, T, F> ExtractIf<'a, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    pub(crate) fn new(vec: &'a mut Vec<T>, range: Range<usize>, pred: F) -> Self {
+        let old_len = vec.len();
+        Self {
+            vec,
+            idx: range.start,
+            end: range.end,
+            del: 0,
+            old_len,
+            pred,
+        }
+    }
+
+    /// Moves the unchecked elements back over the hole and restores the length of `vec`
+    unsafe fn backshift(&mut self) {
+        if self.del > 0 {
+            let src = self.vec.as_mut_ptr().add(self.idx);
+            src.copy_to(src.sub(self.del), self.old_len - self.idx);
+        }
+        self.vec.set_len(self.old_len - self.del);
+    }
+}
+
+impl<T, F> Iterator for ExtractIf<'_, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        unsafe {
+            while self.idx < self.end {
+                let i = self.idx;
+                let v = slice::from_raw_parts_mut(self.vec.as_mut_ptr(), self.old_len);
+                // the last remaining element is never removed
+                let remove = (self.pred)(&mut v[i]) && self.del + 1 < self.old_len;
+                // this is only updated after calling `pred`, so that if it panics,
+                // the element at `i` is still moved back (and dropped) by `Drop`
+                self.idx += 1;
+                if remove {
+                    self.del += 1;
+                    let val = ptr::read(&v[i]);
+                    if self.idx == self.del {
+                        // every checked element was removed, so there is no valid element
+                        // in front of the hole: close it now, so that the length of `vec`
+                        // never has to be 0 (which is what would be left if `self` got leaked)
+                        self.backshift();
+                        self.old_len -= self.del;
+                        self.end -= self.del;
+                        self.idx -= self.del;
+                        self.del = 0;
+                    } else {
+                        self.vec.set_len(self.idx - self.del);
+                    }
+                    return Some(val);
+                } else if self.del > 0 {
+                    ptr::copy_nonoverlapping(&v[i], &mut v[i - self.del], 1);
+                    self.vec.set_len(self.idx - self.del);
+                }
+            }
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.end - self.idx))
+    }
+}
+
+impl<T, F> Drop for ExtractIf<'_, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        unsafe { self.backshift() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ne_vec, NonEmtpyVec};
+    use std::mem;
+    use std::num::NonZeroUsize;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    #[test]
+    fn extract_some() {
+        let mut v = ne_vec![1, 2, 3, 4, 5, 6];
+        let extracted: Vec<_> = v.extract_if(.., |x| *x % 2 == 0).collect();
+        assert_eq!(extracted, [2, 4, 6]);
+        assert_eq!(v, [1, 3, 5]);
+    }
+
+    #[test]
+    fn extract_in_range() {
+        let mut v = ne_vec![2, 4, 6, 8];
+        let extracted: Vec<_> = v.extract_if(1..3, |_| true).collect();
+        assert_eq!(extracted, [4, 6]);
+        assert_eq!(v, [2, 8]);
+    }
+
+    #[test]
+    fn extract_everything_keeps_last() {
+        let mut v = ne_vec![String::from("a"), "b".into(), "c".into()];
+        let extracted: Vec<_> = v.extract_if(.., |_| true).collect();
+        assert_eq!(extracted, ["a", "b"]);
+        assert_eq!(v, ["c"]);
+    }
+
+    #[test]
+    fn early_drop() {
+        let mut v = ne_vec![1, 2, 3, 4];
+        let mut iter = v.extract_if(.., |x| *x != 3);
+        assert_eq!(iter.next(), Some(1));
+        drop(iter);
+        assert_eq!(v, [2, 3, 4]);
+    }
+
+    #[test]
+    fn leak() {
+        let rc = Rc::new(());
+        let mut v = NonEmtpyVec::from_elem(rc.clone(), NonZeroUsize::new(4).unwrap());
+        let mut iter = v.extract_if(.., |_| true);
+        drop(iter.next());
+        drop(iter.next());
+        mem::forget(iter);
+        // every checked element was removed, so nothing had to be left behind
+        assert_eq!(v.len(), 2);
+        v.push(rc.clone());
+        drop(v);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn leak_after_keeping() {
+        let mut v = ne_vec![1, 2, 3, 4];
+        let mut iter = v.extract_if(.., |x| *x == 2);
+        assert_eq!(iter.next(), Some(2));
+        mem::forget(iter);
+        assert_eq!(v[0], 1);
+    }
+
+    #[test]
+    fn panicking_predicate() {
+        let rc = Rc::new(());
+        let mut v = NonEmtpyVec::from_elem(rc.clone(), NonZeroUsize::new(5).unwrap());
+        let mut calls = 0;
+        let res = panic::catch_unwind(AssertUnwindSafe(|| {
+            v.extract_if(.., |_| {
+                calls += 1;
+                if calls == 3 {
+                    panic!();
+                }
+                calls == 2
+            })
+            .for_each(drop);
+        }));
+        assert!(res.is_err());
+        assert_eq!(v.len(), 4);
+        assert_eq!(Rc::strong_count(&rc), 5);
+        drop(v);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn zst() {
+        let mut v = ne_vec![(); 4];
+        assert_eq!(v.extract_if(.., |_| true).count(), 3);
+        assert_eq!(v.len(), 1);
+    }
+}
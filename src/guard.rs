@@ -0,0 +1,52 @@
+use std::fmt;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+
+/// A guard giving mutable access to the `Vec` inside a `NonEmptyVec<T>`
+///
+/// This is returned by [`NonEmptyVec::as_vec_mut`](struct.NonEmtpyVec.html#method.as_vec_mut).
+/// While it is alive, the `NonEmptyVec` only holds a clone of its first element.
+/// When it is dropped, the (possibly modified) `Vec` is put back, unless it is empty.
+/// So if the `Vec` was left empty or the guard is leaked, the `NonEmptyVec` is left containing just that clone
+pub struct NonEmptyVecGuard<'a, T> {
+    target: &'a mut Vec<T>,
+    vec: Vec<T>,
+}
+
+impl<'a, T> NonEmptyVecGuard<'a, T> {
+    /// The caller has to make sure that `placeholder` isn't empty
+    pub(crate) fn new(target: &'a mut Vec<T>, placeholder: Vec<T>) -> Self {
+        let vec = mem::replace(target, placeholder);
+        Self { target, vec }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for NonEmptyVecGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NonEmptyVecGuard").field(&self.vec).finish()
+    }
+}
+
+impl<T> Deref for NonEmptyVecGuard<'_, T> {
+    type Target = Vec<T>;
+
+    #[inline]
+    fn deref(&self) -> &Vec<T> {
+        &self.vec
+    }
+}
+
+impl<T> DerefMut for NonEmptyVecGuard<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        &mut self.vec
+    }
+}
+
+impl<T> Drop for NonEmptyVecGuard<'_, T> {
+    fn drop(&mut self) {
+        if !self.vec.is_empty() {
+            mem::swap(self.target, &mut self.vec);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Conversions from and to [`heapless::Vec`](https://docs.rs/heapless/0.9/heapless/struct.Vec.html) via the `heapless` feature
+
+use crate::{CapacityError, EmptyError, NonEmtpyVec};
+use std::convert::TryFrom;
+
+impl<T, const N: usize> TryFrom<::heapless::Vec<T, N>> for NonEmtpyVec<T> {
+    type Error = EmptyError<::heapless::Vec<T, N>>;
+
+    #[inline]
+    fn try_from(v: ::heapless::Vec<T, N>) -> Result<Self, Self::Error> {
+        if v.is_empty() {
+            Err(EmptyError(v))
+        } else {
+            Ok(Self {
+                inner: v.into_iter().collect(),
+            })
+        }
+    }
+}
+
+impl<T> NonEmtpyVec<T> {
+    /// Moves the elements into a `heapless::Vec<T, N>`
+    ///
+    /// Fails (returning `self` inside the error) if `self.len() > N`
+    #[inline]
+    pub fn try_into_heapless<const N: usize>(
+        self,
+    ) -> Result<::heapless::Vec<T, N>, CapacityError<Self>> {
+        if self.len() > N {
+            Err(CapacityError(self))
+        } else {
+            Ok(self.inner.into_iter().collect())
+        }
+    }
+}
@@ -1,10 +1,62 @@
-use std::borrow::{Borrow, BorrowMut};
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
+#[cfg(feature = "allocator_api")]
+mod allocator;
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+mod chunks;
+mod combinatorics;
+mod drain;
+#[cfg(feature = "serde")]
+pub mod empty_as_none;
+mod extract_if;
+mod guard;
+#[cfg(feature = "heapless")]
+mod heapless;
+#[cfg(feature = "serde")]
+pub mod one_or_many;
+#[cfg(feature = "serde")]
+mod serde;
+mod slice;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+mod splice;
+mod split;
+mod windows;
+
+pub use chunks::{Chunks, RChunks};
+pub use combinatorics::{Combinations, Permutations};
+pub use drain::Drain;
+pub use extract_if::ExtractIf;
+pub use guard::NonEmptyVecGuard;
+pub use slice::NonEmptySlice;
+pub use splice::Splice;
+pub use split::SplitInclusive;
+pub use windows::Windows;
+
+#[cfg(feature = "allocator_api")]
+use std::alloc::{Allocator, Global};
+use std::borrow::{Borrow, BorrowMut, Cow};
+use std::cmp;
+use std::collections::{
+    btree_map, hash_map, BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, VecDeque,
+};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::ffi::{CString, NulError};
+use std::fmt;
+use std::hash::Hash;
 use std::io::{self, IoSlice, Write};
-use std::iter::FromIterator;
+use std::iter::{Cycle, FromIterator};
+use std::mem::{self, ManuallyDrop, MaybeUninit};
 use std::num::NonZeroUsize;
-use std::ops::{Deref, DerefMut, Index, RangeBounds};
+use std::ops::{Bound, Index, IndexMut, Range, RangeBounds};
+#[cfg(not(feature = "allocator_api"))]
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
 use std::slice::SliceIndex;
-use std::vec::Splice;
+use std::string::FromUtf8Error;
+use std::sync::Arc;
 
 macro_rules! copy_fn {
     (@single $v:vis fn $fname:ident(&mut self $(, $arg:ident: $t:ty)*) $(-> $ret:ty)?;) => {
@@ -39,14 +91,210 @@ macro_rules! copy_fn {
     };
 }
 
+/// Makes an `impl` block generic over the allocator when the `allocator_api` feature is enabled
+/// (only usable for methods whose signatures don't mention `Vec`)
+macro_rules! impl_any_alloc {
+    ($(#[$m:meta])* impl<T $(: $bound:path)?> NonEmtpyVec<T> { $($body:tt)* }) => {
+        #[cfg(not(feature = "allocator_api"))]
+        $(#[$m])*
+        impl<T $(: $bound)?> NonEmtpyVec<T> {
+            $($body)*
+        }
+
+        #[cfg(feature = "allocator_api")]
+        $(#[$m])*
+        impl<T $(: $bound)?, A: Allocator> NonEmtpyVec<T, A> {
+            $($body)*
+        }
+    };
+}
+
+/// Creates a [`NonEmtpyVec`](struct.NonEmtpyVec.html) containing the arguments, like [`vec!`](https://doc.rust-lang.org/std/macro.vec.html)
+///
+/// Invoking it with zero elements fails to compile.
+///
+/// # Panics
+/// The `ne_vec![x; n]` form panics if `n == 0`
+#[macro_export]
+macro_rules! ne_vec {
+    () => {
+        compile_error!("ne_vec! requires at least one element")
+    };
+    ($elem:expr; $n:expr) => {
+        match ::std::num::NonZeroUsize::new($n) {
+            Some(n) => $crate::NonEmtpyVec::from_elem($elem, n),
+            None => panic!("ne_vec![x; n] requires n > 0"),
+        }
+    };
+    ($($x:expr),+ $(,)?) => {
+        match $crate::NonEmtpyVec::from_vec(vec![$($x),+]) {
+            Some(v) => v,
+            None => unreachable!(),
+        }
+    };
+}
+
+/// Converts `range` into a `Range`, panicking (like slice indexing would) if it doesn't fit into `0..len`
+fn to_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).expect("range start overflowed"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).expect("range end overflowed"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        start <= end,
+        "range starts at {} but ends at {}",
+        start,
+        end
+    );
+    assert!(
+        end <= len,
+        "range end {} out of bounds for length {}",
+        end,
+        len
+    );
+    start..end
+}
+
+/// The error returned when trying to create a `NonEmptyVec` from something empty
+/// (or when an operation would leave a `NonEmptyVec` without elements)
+///
+/// It holds on to the original value, so nothing is lost
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct EmptyError<C = ()>(C);
+
+impl<C> EmptyError<C> {
+    /// Returns the original (empty) value
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+}
+
+impl<C> fmt::Display for EmptyError<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a NonEmptyVec would have had no elements")
+    }
+}
+
+impl<C: fmt::Debug> Error for EmptyError<C> {}
+
+/// The error returned by [`NonEmptyVec::try_remove`](struct.NonEmtpyVec.html#method.try_remove) and similar functions
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum RemoveError {
+    /// The index was out of bounds
+    OutOfBounds,
+    /// The element to be removed was the only one left
+    LastElement,
+}
+
+impl fmt::Display for RemoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RemoveError::OutOfBounds => f.write_str("removal index out of bounds"),
+            RemoveError::LastElement => f.write_str("tried to remove the last item of NonEmptyVec"),
+        }
+    }
+}
+
+impl Error for RemoveError {}
+
+/// The error returned by [`NonEmptyVec::transpose`](struct.NonEmtpyVec.html#method.transpose) when the rows have different lengths
+///
+/// It holds on to the original value, so nothing is lost
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ShapeError<C = ()>(C);
+
+impl<C> ShapeError<C> {
+    /// Returns the original (non-rectangular) value
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+}
+
+impl<C> fmt::Display for ShapeError<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the rows of a NonEmptyVec had different lengths")
+    }
+}
+
+impl<C: fmt::Debug> Error for ShapeError<C> {}
+
+/// The error returned by [`NonEmptyVec::try_into_heapless`](struct.NonEmtpyVec.html#method.try_into_heapless)
+/// when the target capacity is too small
+///
+/// It holds on to the original value, so nothing is lost
+#[cfg(feature = "heapless")]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct CapacityError<C = ()>(C);
+
+#[cfg(feature = "heapless")]
+impl<C> CapacityError<C> {
+    /// Returns the original value
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<C> fmt::Display for CapacityError<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a NonEmptyVec had more elements than the target capacity")
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<C: fmt::Debug> Error for CapacityError<C> {}
+
+/// An item of [`NonEmptyVec::zip_longest`](struct.NonEmtpyVec.html#method.zip_longest)
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum EitherOrBoth<L, R> {
+    /// Both `NonEmptyVec`s had an element at this position
+    Both(L, R),
+    /// Only the left `NonEmptyVec` had an element at this position
+    Left(L),
+    /// Only the right `NonEmptyVec` had an element at this position
+    Right(R),
+}
+
+/// The result of [`NonEmptyVec::partition`](struct.NonEmtpyVec.html#method.partition)
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum NonEmptyPartition<T> {
+    /// Every element matched the predicate
+    AllMatched(NonEmtpyVec<T>),
+    /// No element matched the predicate
+    NoneMatched(NonEmtpyVec<T>),
+    /// The elements that matched the predicate and the ones that didn't (in that order)
+    Both(NonEmtpyVec<T>, NonEmtpyVec<T>),
+}
+
 /// Like [`Vec<T>`](https://doc.rust-lang.org/std/vec/struct.Vec.html) but guaranteed to have at least one element.
 ///
 /// Undocumented functions work exactly like their `Vec` counterpart
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg(not(feature = "allocator_api"))]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct NonEmtpyVec<T> {
     inner: Vec<T>,
 }
 
+/// Like [`Vec<T, A>`](https://doc.rust-lang.org/std/vec/struct.Vec.html) but guaranteed to have at least one element.
+///
+/// Undocumented functions work exactly like their `Vec` counterpart.
+/// The methods that modify or inspect `self` in place work with any allocator,
+/// most of the ones that build new vectors need the [`Global`](https://doc.rust-lang.org/std/alloc/struct.Global.html) allocator
+#[cfg(feature = "allocator_api")]
+pub struct NonEmtpyVec<T, A: Allocator = Global> {
+    inner: Vec<T, A>,
+}
+
+#[cfg(not(feature = "allocator_api"))]
 impl<T> Deref for NonEmtpyVec<T> {
     type Target = [T];
 
@@ -55,12 +303,14 @@ impl<T> Deref for NonEmtpyVec<T> {
     }
 }
 
+#[cfg(not(feature = "allocator_api"))]
 impl<T> DerefMut for NonEmtpyVec<T> {
     fn deref_mut(&mut self) -> &mut [T] {
         &mut self.inner[..]
     }
 }
 
+#[allow(clippy::len_without_is_empty)]
 impl<T> NonEmtpyVec<T> {
     /// Constructs a new `NonEmptyVec<T>` from a single element
     #[inline]
@@ -68,13 +318,20 @@ impl<T> NonEmtpyVec<T> {
         Self { inner: vec![val] }
     }
 
+    /// Constructs a new `NonEmptyVec<T>` from a single element, with space for at least `capacity` elements
+    ///
+    /// This replaces `Vec::with_capacity`, which would create a vector without any elements
     #[inline]
-    pub fn with_capacity(capacity: NonZeroUsize) -> Self {
-        Self {
-            inner: Vec::with_capacity(capacity.get()),
-        }
+    pub fn with_first_and_capacity(first: T, capacity: NonZeroUsize) -> Self {
+        let mut inner = Vec::with_capacity(capacity.get());
+        inner.push(first);
+        Self { inner }
     }
 
+    /// Works like [`from_raw_parts`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.from_raw_parts)
+    ///
+    /// # Safety
+    /// The same constraints as for [`from_raw_parts`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.from_raw_parts) apply
     #[inline]
     pub unsafe fn from_raw_parts(
         ptr: *mut T,
@@ -86,6 +343,25 @@ impl<T> NonEmtpyVec<T> {
         }
     }
 
+    /// Decomposes `self` into its raw components `(pointer, length, capacity)`
+    ///
+    /// Works like [`into_raw_parts`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.into_raw_parts).
+    /// The memory can be reclaimed via [`from_raw_parts`](#method.from_raw_parts)
+    #[inline]
+    pub fn into_raw_parts(self) -> (*mut T, NonZeroUsize, NonZeroUsize) {
+        let mut inner = ManuallyDrop::new(self.inner);
+        let len = inner.len();
+        let cap = inner.capacity();
+        // this is ok since it relies on the safety guarantee that there is always at least one element
+        unsafe {
+            (
+                inner.as_mut_ptr(),
+                NonZeroUsize::new_unchecked(len),
+                NonZeroUsize::new_unchecked(cap),
+            )
+        }
+    }
+
     /// Constructs a new `NonEmptyVec<T>` from a `Vec` (returns `None` iff the `Vec` is empty)
     #[inline]
     pub fn from_vec(v: Vec<T>) -> Option<Self> {
@@ -96,291 +372,1730 @@ impl<T> NonEmtpyVec<T> {
         }
     }
 
+    /// Constructs a new `NonEmptyVec<T>` from a first element and the rest
+    #[inline]
+    pub fn from_head_and_tail(head: T, mut tail: Vec<T>) -> Self {
+        tail.insert(0, head);
+        Self { inner: tail }
+    }
+
+    /// Constructs a new `NonEmptyVec<T>` from a first element and an iterator yielding the rest
+    #[inline]
+    pub fn collect_with_head<I>(head: T, tail: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let tail = tail.into_iter();
+        let mut inner = Vec::with_capacity(tail.size_hint().0.saturating_add(1));
+        inner.push(head);
+        inner.extend(tail);
+        Self { inner }
+    }
+
+    /// Constructs a new `NonEmptyVec<T>` of length `n` whose elements are `f(0), f(1), ..., f(n - 1)`
+    ///
+    /// Works like [`array::from_fn`](https://doc.rust-lang.org/std/array/fn.from_fn.html)
+    #[inline]
+    pub fn from_fn<F>(n: NonZeroUsize, f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        Self {
+            inner: (0..n.get()).map(f).collect(),
+        }
+    }
+
+    copy_fn! {
+        pub fn into_boxed_slice(self) -> Box<[T]>;
+    }
+
+    /// Like `into_boxed_slice`, but keeps the guarantee that there is at least one element
+    #[inline]
+    pub fn into_boxed_nonempty_slice(self) -> Box<NonEmptySlice<T>> {
+        let raw = Box::into_raw(self.inner.into_boxed_slice());
+        // this is ok since `NonEmptySlice<T>` is `repr(transparent)` over `[T]`
+        // and it relies on the safety guarantee that there is always at least one element
+        unsafe { Box::from_raw(raw as *mut NonEmptySlice<T>) }
+    }
+
+    /// Works like [`leak`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.leak), but returns a `NonEmptySlice`
+    #[inline]
+    pub fn leak<'a>(self) -> &'a mut NonEmptySlice<T> {
+        // this is ok since it relies on the safety guarantee that there is always at least one element
+        unsafe { NonEmptySlice::from_mut_slice_unchecked(self.inner.leak()) }
+    }
+
+    /// Returns the inner `Vec`
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.inner
+    }
+
+    /// Drops every element except the last `n` (which are moved to the front) and returns how many were removed
+    #[inline]
+    pub fn keep_last_n(&mut self, n: NonZeroUsize) -> usize {
+        let removed = self.inner.len().saturating_sub(n.get());
+        self.inner.drain(..removed);
+        removed
+    }
+
+    /// Like the slice method, but takes a `NonZeroUsize` and yields `NonEmptySlice`s
+    ///
+    /// There are no windows if `size` is greater than the length of `self`
+    #[inline]
+    pub fn windows(&self, size: NonZeroUsize) -> Windows<'_, T> {
+        self.as_nonempty_slice().windows(size)
+    }
+
+    /// Like the slice method, but takes a `NonZeroUsize` and yields `NonEmptySlice`s
+    ///
+    /// There is always at least one chunk
+    #[inline]
+    pub fn chunks(&self, size: NonZeroUsize) -> Chunks<'_, T> {
+        self.as_nonempty_slice().chunks(size)
+    }
+
+    /// Like the slice method, but takes a `NonZeroUsize` and yields `NonEmptySlice`s
+    ///
+    /// There is always at least one chunk
+    #[inline]
+    pub fn rchunks(&self, size: NonZeroUsize) -> RChunks<'_, T> {
+        self.as_nonempty_slice().rchunks(size)
+    }
+
+    /// Works like the slice method
+    ///
+    /// There is always at least one segment, but the segments themselves may be empty
+    #[inline]
+    pub fn split<F: FnMut(&T) -> bool>(&self, pred: F) -> std::slice::Split<'_, T, F> {
+        self.inner.split(pred)
+    }
+
+    /// Like the slice method, but yields `NonEmptySlice`s
+    ///
+    /// There is always at least one segment
+    #[inline]
+    pub fn split_inclusive<F: FnMut(&T) -> bool>(&self, pred: F) -> SplitInclusive<'_, T, F> {
+        self.as_nonempty_slice().split_inclusive(pred)
+    }
+
+    /// Returns an iterator over all orderings of the elements
+    ///
+    /// There is always at least one permutation
+    #[inline]
+    pub fn permutations(&self) -> Permutations<'_, T> {
+        self.as_nonempty_slice().permutations()
+    }
+
+    /// Returns an iterator over all selections of `k` elements
+    ///
+    /// There are no combinations if `k` is greater than the length of `self`
+    #[inline]
+    pub fn combinations(&self, k: NonZeroUsize) -> Combinations<'_, T> {
+        self.as_nonempty_slice().combinations(k)
+    }
+
+    copy_fn! {
+        pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>];
+    }
+
+    /// Inserts all elements of `iter` at `index`, which is faster than calling `insert` for each one
+    ///
+    /// # Panics
+    /// Panics if `index > self.len()`
+    #[inline]
+    pub fn insert_many<I>(&mut self, index: usize, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        drop(self.inner.splice(index..index, iter));
+    }
+
+    /// Works like [`drain`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.drain) with one slight change
+    ///
+    /// Returns an error (and leaves `self` untouched) if `range` covers every element
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds
+    #[inline]
+    pub fn drain<R>(&mut self, range: R) -> Result<Drain<'_, T>, EmptyError>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = to_range(range, self.inner.len());
+        if range.end - range.start == self.inner.len() {
+            Err(EmptyError(()))
+        } else {
+            Ok(Drain::new(&mut self.inner, range))
+        }
+    }
+
+    /// Drains every element except the first one
+    #[inline]
+    pub fn drain_tail(&mut self) -> Drain<'_, T> {
+        let len = self.inner.len();
+        Drain::new(&mut self.inner, 1..len)
+    }
+
+    /// Works like [`extract_if`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.extract_if) with one slight change
+    ///
+    /// If this would remove every element, the last one is kept instead
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds
+    #[inline]
+    pub fn extract_if<F, R>(&mut self, range: R, filter: F) -> ExtractIf<'_, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+        R: RangeBounds<usize>,
+    {
+        let range = to_range(range, self.inner.len());
+        ExtractIf::new(&mut self.inner, range, filter)
+    }
+
+    /// Removes up to `n` elements from the back (but never the first one) and returns them in their original order
+    #[inline]
+    pub fn pop_many(&mut self, n: usize) -> Vec<T> {
+        let at = self.inner.len().saturating_sub(n).max(1);
+        self.inner.split_off(at)
+    }
+
+    /// Removes the last element (even if it is the only one) and returns it along with the rest
+    #[inline]
+    pub fn into_pop(mut self) -> (Vec<T>, T) {
+        match self.inner.pop() {
+            Some(last) => (self.inner, last),
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns the last element, dropping the rest
+    #[inline]
+    pub fn into_last(self) -> T {
+        self.into_pop().1
+    }
+
+    #[inline]
+    pub fn append_vec(&mut self, other: &mut Vec<T>) {
+        self.inner.append(other)
+    }
+
+    /// Returns an iterator that repeats the elements of `self` forever
+    ///
+    /// Since `self` is never empty, this iterator never ends
+    #[inline]
+    pub fn cycle(&self) -> Cycle<std::slice::Iter<'_, T>> {
+        self.inner.iter().cycle()
+    }
+
+    /// Returns the only element iff `self.len() == 1`, otherwise gives back `self`
+    #[inline]
+    pub fn into_single(mut self) -> Result<T, Self> {
+        if self.inner.len() == 1 {
+            Ok(self.inner.swap_remove(0))
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Like [`Iterator::min_by`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.min_by), but doesn't need to return an `Option`
+    #[inline]
+    pub fn min_by<F>(&self, mut compare: F) -> &T
+    where
+        F: FnMut(&T, &T) -> cmp::Ordering,
+    {
+        let (first, rest) = self.split_first();
+        rest.iter().fold(first, |min, x| match compare(min, x) {
+            cmp::Ordering::Greater => x,
+            _ => min,
+        })
+    }
+
+    /// Like [`Iterator::max_by`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.max_by), but doesn't need to return an `Option`
+    #[inline]
+    pub fn max_by<F>(&self, mut compare: F) -> &T
+    where
+        F: FnMut(&T, &T) -> cmp::Ordering,
+    {
+        let (first, rest) = self.split_first();
+        rest.iter().fold(first, |max, x| match compare(max, x) {
+            cmp::Ordering::Greater => max,
+            _ => x,
+        })
+    }
+
+    /// Like [`Iterator::min_by_key`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.min_by_key), but doesn't need to return an `Option`
+    #[inline]
+    pub fn min_by_key<F, K>(&self, mut key: F) -> &T
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        let (first, rest) = self.split_first();
+        let init = (key(first), first);
+        rest.iter()
+            .map(|x| (key(x), x))
+            .fold(init, |min, x| if x.0 < min.0 { x } else { min })
+            .1
+    }
+
+    /// Like [`Iterator::max_by_key`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.max_by_key), but doesn't need to return an `Option`
+    #[inline]
+    pub fn max_by_key<F, K>(&self, mut key: F) -> &T
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        let (first, rest) = self.split_first();
+        let init = (key(first), first);
+        rest.iter()
+            .map(|x| (key(x), x))
+            .fold(init, |max, x| if x.0 < max.0 { max } else { x })
+            .1
+    }
+
+    /// Returns `(self.min_by_key(key), self.max_by_key(key))`, but only traverses `self` once
+    /// (and calls `key` only once per element)
+    #[inline]
+    pub fn minmax_by_key<F, K>(&self, mut key: F) -> (&T, &T)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord + Clone,
+    {
+        let (first, rest) = self.split_first();
+        let k = key(first);
+        let init = ((k.clone(), first), (k, first));
+        let (min, max) = rest.iter().fold(init, |(min, max), x| {
+            let k = key(x);
+            if k < min.0 {
+                ((k, x), max)
+            } else if k < max.0 {
+                (min, max)
+            } else {
+                (min, (k, x))
+            }
+        });
+        (min.1, max.1)
+    }
+
+    /// Like [`insert_sorted`](#method.insert_sorted), but for a `self` that is sorted by `key`
+    #[inline]
+    pub fn insert_sorted_by_key<F, K>(&mut self, value: T, mut key: F) -> usize
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        let k = key(&value);
+        let index = self.inner.partition_point(|x| key(x) <= k);
+        self.inner.insert(index, value);
+        index
+    }
+
+    /// Sorts `self` by `key` and then removes consecutive elements with equal keys (keeping the first one)
+    ///
+    /// There is always at least one element left
+    #[inline]
+    pub fn sort_and_dedup_by_key<F, K>(&mut self, mut key: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.inner.sort_by_key(&mut key);
+        self.inner.dedup_by_key(|x| key(x));
+    }
+
+    /// Merges two vectors that are sorted according to `compare` into one sorted vector
+    ///
+    /// For equal elements, those from `self` come first
+    #[inline]
+    pub fn merge_sorted_by<F>(self, other: Self, mut compare: F) -> Self
+    where
+        F: FnMut(&T, &T) -> cmp::Ordering,
+    {
+        let mut inner = Vec::with_capacity(self.inner.len() + other.inner.len());
+        let mut a = self.inner.into_iter().peekable();
+        let mut b = other.inner.into_iter().peekable();
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            if compare(x, y) == cmp::Ordering::Greater {
+                inner.extend(b.next());
+            } else {
+                inner.extend(a.next());
+            }
+        }
+        inner.extend(a);
+        inner.extend(b);
+        Self { inner }
+    }
+
+    /// Like [`merge_sorted_by`](#method.merge_sorted_by), but for vectors sorted by `key`
+    #[inline]
+    pub fn merge_sorted_by_key<F, K>(self, other: Self, mut key: F) -> Self
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.merge_sorted_by(other, |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Returns the index of the element that gives the minimum key (the first one if there are several)
+    #[inline]
+    pub fn position_min_by_key<F, K>(&self, mut key: F) -> usize
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        let (first, rest) = self.split_first();
+        let init = (key(first), 0);
+        rest.iter()
+            .enumerate()
+            .map(|(i, x)| (key(x), i + 1))
+            .fold(init, |min, x| if x.0 < min.0 { x } else { min })
+            .1
+    }
+
+    /// Returns the index of the element that gives the maximum key (the last one if there are several)
+    #[inline]
+    pub fn position_max_by_key<F, K>(&self, mut key: F) -> usize
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        let (first, rest) = self.split_first();
+        let init = (key(first), 0);
+        rest.iter()
+            .enumerate()
+            .map(|(i, x)| (key(x), i + 1))
+            .fold(init, |max, x| if x.0 < max.0 { max } else { x })
+            .1
+    }
+
+    #[cfg(not(feature = "allocator_api"))]
+    #[inline]
+    pub fn split_off(&mut self, at: NonZeroUsize) -> Vec<T> {
+        self.inner.split_off(at.get())
+    }
+
+    /// Splits `self` into the elements before `at` and those from `at` onwards
+    ///
+    /// # Panics
+    /// Panics if `at > self.len()`
+    #[inline]
+    pub fn divide_at(mut self, at: NonZeroUsize) -> (Self, Vec<T>) {
+        let back = self.inner.split_off(at.get());
+        (self, back)
+    }
+
+    /// Like [`divide_at`](#method.divide_at), but both halves are guaranteed to be non-empty
+    ///
+    /// Gives back `self` if `at >= self.len()`
+    #[inline]
+    pub fn try_divide_at(mut self, at: NonZeroUsize) -> Result<(Self, Self), Self> {
+        if at.get() < self.inner.len() {
+            let back = self.inner.split_off(at.get());
+            Ok((self, Self { inner: back }))
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Splits `self` into its first element and the rest
+    ///
+    /// This is the inverse of [`from_head_and_tail`](#method.from_head_and_tail)
+    #[inline]
+    pub fn into_parts(self) -> (T, Vec<T>) {
+        let mut tail = self.inner;
+        let head = tail.remove(0);
+        (head, tail)
+    }
+
+    #[inline]
+    pub fn resize_with<F>(&mut self, new_len: NonZeroUsize, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.inner.resize_with(new_len.get(), f)
+    }
+
+    /// Like [`resize_with`](#method.resize_with), but never shrinks `self`
+    #[inline]
+    pub fn pad_to_with<F>(&mut self, min_len: NonZeroUsize, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        if self.inner.len() < min_len.get() {
+            self.inner.resize_with(min_len.get(), f)
+        }
+    }
+
+    /// Creates a new `NonEmptyVec` by applying `f` to every element of `self`
+    ///
+    /// This collects straight from the inner `Vec`'s `IntoIter`,
+    /// so the allocation of `self` can be reused when `T` and `U` have the same size and alignment
+    /// (which is an implementation detail of `Vec`'s `FromIterator` impl).
+    /// The same goes for [`try_map`](#method.try_map) and [`filter_map`](#method.filter_map)
+    #[inline]
+    pub fn map<F: FnMut(T) -> U, U>(self, f: F) -> NonEmtpyVec<U> {
+        NonEmtpyVec {
+            inner: self.inner.into_iter().map(f).collect(),
+        }
+    }
+
+    /// Like [`map`](#method.map), but threads a state through the calls to `f`.
+    /// Works like [`Iterator::scan`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.scan), but keeps every element
+    #[inline]
+    pub fn map_accum<F: FnMut(&mut S, T) -> U, S, U>(self, init: S, mut f: F) -> NonEmtpyVec<U> {
+        let mut state = init;
+        NonEmtpyVec {
+            inner: self.inner.into_iter().map(|x| f(&mut state, x)).collect(),
+        }
+    }
+
+    /// Like [`map`](#method.map), but borrows `self` instead of consuming it
+    #[inline]
+    pub fn map_ref<F: FnMut(&T) -> U, U>(&self, f: F) -> NonEmtpyVec<U> {
+        NonEmtpyVec {
+            inner: self.inner.iter().map(f).collect(),
+        }
+    }
+
+    /// Like [`map`](#method.map), but stops at the first error `f` returns
+    #[inline]
+    pub fn try_map<F: FnMut(T) -> Result<U, E>, U, E>(self, f: F) -> Result<NonEmtpyVec<U>, E> {
+        Ok(NonEmtpyVec {
+            inner: self.inner.into_iter().map(f).collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Applies `f` to every element of `self`, keeping only the `Some` results
+    ///
+    /// Returns `None` if `f` returned `None` for every element
+    #[inline]
+    pub fn filter_map<F: FnMut(T) -> Option<U>, U>(self, f: F) -> Option<NonEmtpyVec<U>> {
+        NonEmtpyVec::from_vec(self.inner.into_iter().filter_map(f).collect())
+    }
+
+    /// Applies `f` to every element of `self`, reusing the allocation
+    ///
+    /// # Panics
+    /// If `f` panics, the element it was called on is lost and the elements behind it move one position to the front.
+    /// If it was the only element, the process is aborted instead, since `self` can't be left empty
+    #[inline]
+    pub fn map_in_place<F: FnMut(T) -> T>(&mut self, mut f: F) {
+        /// Closes the hole at `idx` if `f` panics
+        struct Hole<'a, T> {
+            vec: &'a mut Vec<T>,
+            idx: usize,
+        }
+
+        impl<T> Drop for Hole<'_, T> {
+            fn drop(&mut self) {
+                let len = self.vec.len();
+                if len == 1 {
+                    std::process::abort();
+                }
+                // this is ok since the element at `idx` has been moved out and everything behind it is still valid
+                unsafe {
+                    let hole = self.vec.as_mut_ptr().add(self.idx);
+                    std::ptr::copy(hole.add(1), hole, len - self.idx - 1);
+                    self.vec.set_len(len - 1);
+                }
+            }
+        }
+
+        for idx in 0..self.inner.len() {
+            let hole = Hole {
+                vec: &mut self.inner,
+                idx,
+            };
+            // this is ok since `hole` takes care of the moved-out element if `f` panics
+            unsafe {
+                let elem = hole.vec.as_mut_ptr().add(idx);
+                let new = f(std::ptr::read(elem));
+                std::mem::forget(hole);
+                std::ptr::write(elem, new);
+            }
+        }
+    }
+
+    /// Works like `.map(f).flatten()`
+    ///
+    /// Since every element is mapped to at least one new element, the result is never empty
+    #[inline]
+    pub fn flat_map<F: FnMut(T) -> NonEmtpyVec<U>, U>(self, mut f: F) -> NonEmtpyVec<U> {
+        NonEmtpyVec {
+            inner: self.inner.into_iter().flat_map(|x| f(x).inner).collect(),
+        }
+    }
+
+    /// Splits `self` into the elements that match `pred` and the ones that don't.
+    /// Works like [`Iterator::partition`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.partition),
+    /// but keeps track of which sides are non-empty
+    #[inline]
+    pub fn partition<F: FnMut(&T) -> bool>(self, pred: F) -> NonEmptyPartition<T> {
+        let (matched, unmatched): (Vec<T>, Vec<T>) = self.inner.into_iter().partition(pred);
+        match (
+            NonEmtpyVec::from_vec(matched),
+            NonEmtpyVec::from_vec(unmatched),
+        ) {
+            (Some(m), Some(u)) => NonEmptyPartition::Both(m, u),
+            (Some(m), None) => NonEmptyPartition::AllMatched(m),
+            (None, Some(u)) => NonEmptyPartition::NoneMatched(u),
+            (None, None) => unreachable!(),
+        }
+    }
+
+    /// Splits `self` into groups of adjacent elements for which `key` returns equal values.
+    /// Works like [`chunk_by`](https://doc.rust-lang.org/std/primitive.slice.html#method.chunk_by), but consumes `self`
+    #[inline]
+    pub fn group_consecutive_by<F: FnMut(&T) -> K, K: PartialEq>(
+        self,
+        mut key: F,
+    ) -> NonEmtpyVec<NonEmtpyVec<T>> {
+        let mut iter = self.inner.into_iter();
+        let first = match iter.next() {
+            Some(x) => x,
+            None => unreachable!(),
+        };
+        let mut last_key = key(&first);
+        let mut groups = Vec::new();
+        let mut group = vec![first];
+        for x in iter {
+            let k = key(&x);
+            if k != last_key {
+                groups.push(NonEmtpyVec {
+                    inner: std::mem::take(&mut group),
+                });
+                last_key = k;
+            }
+            group.push(x);
+        }
+        groups.push(NonEmtpyVec { inner: group });
+        NonEmtpyVec { inner: groups }
+    }
+
+    /// Splits `self` into chunks of `size` elements (except for the last one, which may be shorter).
+    /// Works like [`chunks`](https://doc.rust-lang.org/std/primitive.slice.html#method.chunks), but consumes `self`
+    #[inline]
+    pub fn into_chunks(self, size: NonZeroUsize) -> NonEmtpyVec<NonEmtpyVec<T>> {
+        let size = size.get();
+        let mut chunks = Vec::with_capacity(self.inner.len().div_ceil(size));
+        let mut iter = self.inner.into_iter();
+        loop {
+            let chunk: Vec<T> = iter.by_ref().take(size).collect();
+            if chunk.is_empty() {
+                break;
+            }
+            chunks.push(NonEmtpyVec { inner: chunk });
+        }
+        NonEmtpyVec { inner: chunks }
+    }
+
+    /// Sorts the elements into groups by the value `key` returns for them, preserving their order within each group
+    #[inline]
+    pub fn group_by_key<F: FnMut(&T) -> K, K: Hash + Eq>(
+        self,
+        mut key: F,
+    ) -> HashMap<K, NonEmtpyVec<T>> {
+        let mut groups = HashMap::<K, NonEmtpyVec<T>>::new();
+        for x in self.inner {
+            match groups.entry(key(&x)) {
+                hash_map::Entry::Occupied(mut e) => e.get_mut().push(x),
+                hash_map::Entry::Vacant(e) => {
+                    e.insert(NonEmtpyVec { inner: vec![x] });
+                }
+            }
+        }
+        groups
+    }
+
+    /// Like [`group_by_key`](#method.group_by_key), but returns a `BTreeMap`
+    #[inline]
+    pub fn group_by_key_btree<F: FnMut(&T) -> K, K: Ord>(
+        self,
+        mut key: F,
+    ) -> BTreeMap<K, NonEmtpyVec<T>> {
+        let mut groups = BTreeMap::<K, NonEmtpyVec<T>>::new();
+        for x in self.inner {
+            match groups.entry(key(&x)) {
+                btree_map::Entry::Occupied(mut e) => e.get_mut().push(x),
+                btree_map::Entry::Vacant(e) => {
+                    e.insert(NonEmtpyVec { inner: vec![x] });
+                }
+            }
+        }
+        groups
+    }
+
+    /// Pairs up the elements of `self` and `other`, stopping at the end of the shorter one.
+    /// Works like [`Iterator::zip`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.zip)
+    #[inline]
+    pub fn zip<U>(self, other: NonEmtpyVec<U>) -> NonEmtpyVec<(T, U)> {
+        NonEmtpyVec {
+            inner: self.inner.into_iter().zip(other.inner).collect(),
+        }
+    }
+
+    /// Pairs up the elements of `self` and `other` like [`zip`](#method.zip), but continues until the end of the longer one
+    #[inline]
+    pub fn zip_longest<U>(self, other: NonEmtpyVec<U>) -> NonEmtpyVec<EitherOrBoth<T, U>> {
+        let mut left = self.inner.into_iter();
+        let mut right = other.inner.into_iter();
+        let mut inner = Vec::with_capacity(cmp::max(left.len(), right.len()));
+        loop {
+            inner.push(match (left.next(), right.next()) {
+                (Some(l), Some(r)) => EitherOrBoth::Both(l, r),
+                (Some(l), None) => EitherOrBoth::Left(l),
+                (None, Some(r)) => EitherOrBoth::Right(r),
+                (None, None) => break,
+            });
+        }
+        NonEmtpyVec { inner }
+    }
+
+    /// Places an element generated by `sep` between every two adjacent elements
+    #[inline]
+    pub fn intersperse_with<F: FnMut() -> T>(self, mut sep: F) -> Self {
+        let mut inner = Vec::with_capacity(2 * self.inner.len() - 1);
+        let mut iter = self.inner.into_iter();
+        inner.extend(iter.next());
+        for x in iter {
+            inner.push(sep());
+            inner.push(x);
+        }
+        NonEmtpyVec { inner }
+    }
+
+    /// Folds the elements, using the first one as the initial accumulator
+    ///
+    /// Like [`Iterator::reduce`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.reduce), but doesn't need to return an `Option`
+    #[inline]
+    pub fn reduce<F: FnMut(T, T) -> T>(self, f: F) -> T {
+        match self.inner.into_iter().reduce(f) {
+            Some(x) => x,
+            None => unreachable!(),
+        }
+    }
+
+    /// Creates a `NonEmptyVec` whose elements are references to this `NonEmptyVec`'s elements
+    #[inline]
+    pub fn new_ref(&self) -> NonEmtpyVec<&T> {
+        NonEmtpyVec {
+            inner: self.inner.iter().collect(),
+        }
+    }
+
+    /// Creates a `NonEmptyVec` whose elements are mutable references to this `NonEmptyVec`'s elements
+    #[inline]
+    pub fn new_mut(&mut self) -> NonEmtpyVec<&mut T> {
+        NonEmtpyVec {
+            inner: self.inner.iter_mut().collect(),
+        }
+    }
+}
+
+impl_any_alloc! {
+    #[allow(clippy::len_without_is_empty)]
+    impl<T> NonEmtpyVec<T> {
+        #[inline]
+        pub fn capacity(&self) -> NonZeroUsize {
+            // this is ok since it relies on the safety guarantee that there is always at least one element
+            unsafe { NonZeroUsize::new_unchecked(self.inner.capacity()) }
+        }
+
+        copy_fn! {
+            pub fn reserve(&mut self, additional: usize);
+            pub fn reserve_exact(&mut self, additional: usize);
+            pub fn shrink_to_fit(&mut self);
+        }
+
+        #[inline]
+        pub fn truncate(&mut self, len: NonZeroUsize) {
+            self.inner.truncate(len.get())
+        }
+
+        /// Drops every element except the first one and returns how many were removed
+        #[inline]
+        pub fn keep_first(&mut self) -> usize {
+            let removed = self.inner.len() - 1;
+            self.inner.truncate(1);
+            removed
+        }
+
+        /// Like `truncate`, but returns how many elements were removed
+        #[inline]
+        pub fn keep_first_n(&mut self, n: NonZeroUsize) -> usize {
+            let removed = self.inner.len().saturating_sub(n.get());
+            self.inner.truncate(n.get());
+            removed
+        }
+
+        copy_fn! {
+            pub fn as_slice(&self) -> &[T];
+            pub fn as_mut_slice(&mut self) -> &mut [T];
+            pub fn as_ptr(&self) -> *const T;
+            pub fn as_mut_ptr(&mut self) -> *mut T;
+        }
+
+        /// Like `as_slice`, but keeps the guarantee that there is at least one element
+        #[inline]
+        pub fn as_nonempty_slice(&self) -> &NonEmptySlice<T> {
+            // this is ok since it relies on the safety guarantee that there is always at least one element
+            unsafe { NonEmptySlice::from_slice_unchecked(&self.inner) }
+        }
+
+        /// Like `as_mut_slice`, but keeps the guarantee that there is at least one element
+        #[inline]
+        pub fn as_mut_nonempty_slice(&mut self) -> &mut NonEmptySlice<T> {
+            // this is ok since it relies on the safety guarantee that there is always at least one element
+            unsafe { NonEmptySlice::from_mut_slice_unchecked(&mut self.inner) }
+        }
+
+        /// Works like [`set_len`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.set_len) with one slight change
+        ///
+        /// # Safety
+        /// This is (additionally to the constraints of [`set_len`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.set_len)) only safe when `new_len > 0`
+        #[inline]
+        pub unsafe fn set_len(&mut self, new_len: usize) {
+            self.inner.set_len(new_len)
+        }
+
+        /// Marks the first `additional` elements of the spare capacity as initialized, making them part of `self`
+        ///
+        /// Unlike [`set_len`](#method.set_len), this can only make `self` longer,
+        /// so it can never break the guarantee that there is at least one element
+        ///
+        /// # Safety
+        /// The first `additional` elements of [`spare_capacity_mut`](#method.spare_capacity_mut) must have been initialized
+        #[inline]
+        pub unsafe fn extend_from_spare(&mut self, additional: usize) {
+            let new_len = self.inner.len() + additional;
+            debug_assert!(new_len <= self.inner.capacity());
+            self.inner.set_len(new_len)
+        }
+
+        /// Works like [`swap_remove`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.swap_remove) with one slight change
+        ///
+        /// # Panics
+        /// Panics if `self.len() == 1`
+        #[inline]
+        pub fn swap_remove(&mut self, index: usize) -> T {
+            assert!(
+                self.len() > 1,
+                "tried to remove the last item of NonEmptyVec"
+            );
+            self.inner.swap_remove(index)
+        }
+
+        /// Like [`swap_remove`](#method.swap_remove), but returns an error instead of panicking
+        #[inline]
+        pub fn try_swap_remove(&mut self, index: usize) -> Result<T, RemoveError> {
+            self.check_remove(index)?;
+            Ok(self.inner.swap_remove(index))
+        }
+
+        copy_fn! {
+            pub fn insert(&mut self, index: usize, element: T);
+        }
+
+        /// Rotates `self` to the left so that the element at `index` becomes the first one
+        ///
+        /// # Panics
+        /// Panics if `index >= self.len()`
+        #[inline]
+        pub fn rotate_to(&mut self, index: usize) {
+            assert!(
+                index < self.inner.len(),
+                "rotate_to index {} out of bounds for length {}",
+                index,
+                self.inner.len()
+            );
+            self.inner.rotate_left(index)
+        }
+
+        /// Replaces the element at `index` with `value` and returns the old one
+        ///
+        /// # Panics
+        /// Panics if `index >= self.len()`
+        #[inline]
+        pub fn replace(&mut self, index: usize, value: T) -> T {
+            mem::replace(&mut self.inner[index], value)
+        }
+
+        /// Works like [`remove`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.remove) with one slight change
+        ///
+        /// # Panics
+        /// Panics if `self.len() == 1`
+        #[inline]
+        pub fn remove(&mut self, index: usize) -> T {
+            assert!(
+                self.len() > 1,
+                "tried to remove the last item of NonEmptyVec"
+            );
+            self.inner.remove(index)
+        }
+
+        /// Like [`remove`](#method.remove), but returns an error instead of panicking
+        #[inline]
+        pub fn try_remove(&mut self, index: usize) -> Result<T, RemoveError> {
+            self.check_remove(index)?;
+            Ok(self.inner.remove(index))
+        }
+
+        #[inline]
+        fn check_remove(&self, index: usize) -> Result<(), RemoveError> {
+            if index >= self.inner.len() {
+                Err(RemoveError::OutOfBounds)
+            } else if self.inner.len() == 1 {
+                Err(RemoveError::LastElement)
+            } else {
+                Ok(())
+            }
+        }
+
+        /// Works like [`retain`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.retain) with one slight change
+        ///
+        /// # Panics
+        /// Panics if there would be no items left (in which case `self` is left untouched)
+        #[inline]
+        pub fn retain<F>(&mut self, f: F)
+        where
+            F: FnMut(&T) -> bool,
+        {
+            self.try_retain(f)
+                .expect("no items left after NonEmptyVec::retain")
+        }
+
+        /// Like [`retain`](#method.retain), but instead of panicking it leaves `self` untouched
+        /// if no items would be left
+        ///
+        /// `f` is still called exactly once per element, in order
+        #[inline]
+        pub fn try_retain<F>(&mut self, mut f: F) -> Result<(), EmptyError>
+        where
+            F: FnMut(&T) -> bool,
+        {
+            let keep: Vec<bool> = self.inner.iter().map(&mut f).collect();
+            if !keep.contains(&true) {
+                return Err(EmptyError(()));
+            }
+            let mut keep = keep.into_iter();
+            self.inner.retain(|_| keep.next() == Some(true));
+            Ok(())
+        }
+
+        #[inline]
+        pub fn dedup_by_key<F, K>(&mut self, key: F)
+        where
+            F: FnMut(&mut T) -> K,
+            K: PartialEq<K>,
+        {
+            self.inner.dedup_by_key(key);
+        }
+
+        #[inline]
+        pub fn dedup_by<F>(&mut self, same_bucket: F)
+        where
+            F: FnMut(&mut T, &mut T) -> bool,
+        {
+            self.inner.dedup_by(same_bucket)
+        }
+
+        copy_fn!(pub fn push(&mut self, value: T););
+
+        /// Works like [`push_within_capacity`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.push_within_capacity)
+        ///
+        /// Appends `value` only if that doesn't require reallocating, otherwise gives it back
+        #[inline]
+        pub fn push_within_capacity(&mut self, value: T) -> Result<(), T> {
+            if self.inner.len() < self.inner.capacity() {
+                self.inner.push(value);
+                Ok(())
+            } else {
+                Err(value)
+            }
+        }
+
+        /// Inserts `value` at the front, making it the new first element
+        #[inline]
+        pub fn push_front(&mut self, value: T) {
+            self.inner.insert(0, value)
+        }
+
+        /// Will not pop the last item, instead returns `None`
+        #[inline]
+        pub fn pop(&mut self) -> Option<T> {
+            if self.inner.len() < 2 {
+                None
+            } else {
+                self.inner.pop()
+            }
+        }
+
+        /// Works like [`append`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.append) with one slight change
+        ///
+        /// `other` is taken by value, since it would be left empty otherwise
+        #[inline]
+        pub fn append(&mut self, mut other: Self) {
+            self.inner.append(&mut other.inner)
+        }
+
+        copy_fn!(pub fn len(&self) -> usize;);
+
+        /// Like `len`, but as a `NonZeroUsize`
+        #[inline]
+        pub fn len_nonzero(&self) -> NonZeroUsize {
+            self.as_nonempty_slice().len_nonzero()
+        }
+
+        /// Like the slice method, but doesn't need to return an `Option`
+        #[inline]
+        pub fn first(&self) -> &T {
+            self.as_nonempty_slice().first()
+        }
+
+        /// Like the slice method, but doesn't need to return an `Option`
+        #[inline]
+        pub fn last(&self) -> &T {
+            self.as_nonempty_slice().last()
+        }
+
+        /// Like the slice method, but doesn't need to return an `Option`
+        #[inline]
+        pub fn first_mut(&mut self) -> &mut T {
+            self.as_mut_nonempty_slice().first_mut()
+        }
+
+        /// Like the slice method, but doesn't need to return an `Option`
+        #[inline]
+        pub fn last_mut(&mut self) -> &mut T {
+            self.as_mut_nonempty_slice().last_mut()
+        }
+
+        /// Like the slice method, but doesn't need to return an `Option`
+        #[inline]
+        pub fn split_first(&self) -> (&T, &[T]) {
+            self.as_nonempty_slice().split_first()
+        }
+
+        /// Like the slice method, but doesn't need to return an `Option`
+        #[inline]
+        pub fn split_first_mut(&mut self) -> (&mut T, &mut [T]) {
+            self.as_mut_nonempty_slice().split_first_mut()
+        }
+
+        /// Like the slice method, but doesn't need to return an `Option`
+        #[inline]
+        pub fn split_last(&self) -> (&T, &[T]) {
+            self.as_nonempty_slice().split_last()
+        }
+
+        /// Like the slice method, but doesn't need to return an `Option`
+        #[inline]
+        pub fn split_last_mut(&mut self) -> (&mut T, &mut [T]) {
+            self.as_mut_nonempty_slice().split_last_mut()
+        }
+
+        /// Borrows the first element and the rest mutably at the same time
+        ///
+        /// This is the same as [`split_first_mut`](#method.split_first_mut)
+        #[inline]
+        pub fn first_rest_mut(&mut self) -> (&mut T, &mut [T]) {
+            self.split_first_mut()
+        }
+
+        /// Borrows the last element and the rest mutably at the same time
+        ///
+        /// This is the same as [`split_last_mut`](#method.split_last_mut)
+        #[inline]
+        pub fn last_rest_mut(&mut self) -> (&mut T, &mut [T]) {
+            self.split_last_mut()
+        }
+
+        /// Returns everything after the first element
+        #[inline]
+        pub fn tail(&self) -> &[T] {
+            self.as_nonempty_slice().tail()
+        }
+
+        /// Returns everything after the first element
+        #[inline]
+        pub fn tail_mut(&mut self) -> &mut [T] {
+            self.as_mut_nonempty_slice().tail_mut()
+        }
+
+        /// Returns everything before the last element
+        #[inline]
+        pub fn init(&self) -> &[T] {
+            self.as_nonempty_slice().init()
+        }
+
+        /// Returns the element at `index % self.len()`, which always exists
+        #[inline]
+        pub fn get_wrapped(&self, index: usize) -> &T {
+            &self.inner[index % self.inner.len()]
+        }
+
+        /// Returns the element at `index % self.len()`, which always exists
+        #[inline]
+        pub fn get_wrapped_mut(&mut self, index: usize) -> &mut T {
+            let len = self.inner.len();
+            &mut self.inner[index % len]
+        }
+
+        /// Returns the only element iff `self.len() == 1`
+        #[inline]
+        pub fn as_single(&self) -> Option<&T> {
+            self.as_nonempty_slice().as_single()
+        }
+    }
+}
+
+impl<T> NonEmtpyVec<T>
+where
+    T: Clone,
+{
+    /// Constructs a new `NonEmptyVec<T>` containing `n` clones of `value`
+    #[inline]
+    pub fn from_elem(value: T, n: NonZeroUsize) -> Self {
+        Self {
+            inner: vec![value; n.get()],
+        }
+    }
+
+    /// Constructs a new `NonEmptyVec<T>` from a `Cow` (returns `None` iff it is empty)
+    ///
+    /// This only clones the elements if the `Cow` is borrowed
+    #[inline]
+    pub fn from_cow(c: Cow<'_, [T]>) -> Option<Self> {
+        if c.is_empty() {
+            None
+        } else {
+            Some(Self {
+                inner: c.into_owned(),
+            })
+        }
+    }
+
+    /// Constructs a new `NonEmptyVec<T>` by cloning the elements of a slice (returns `None` iff the slice is empty)
+    #[inline]
+    pub fn from_slice(s: &[T]) -> Option<Self> {
+        if s.is_empty() {
+            None
+        } else {
+            Some(Self { inner: s.to_vec() })
+        }
+    }
+
+    #[inline]
+    pub fn resize(&mut self, new_len: NonZeroUsize, value: T) {
+        self.inner.resize(new_len.get(), value)
+    }
+
+    /// Like [`resize`](#method.resize), but never shrinks `self`
+    #[inline]
+    pub fn pad_to(&mut self, min_len: NonZeroUsize, value: T) {
+        if self.inner.len() < min_len.get() {
+            self.inner.resize(min_len.get(), value)
+        }
+    }
+
+    copy_fn!(pub fn extend_from_slice(&mut self, other: &[T]););
+
+    #[inline]
+    pub fn extend_from_within<R>(&mut self, src: R)
+    where
+        R: RangeBounds<usize>,
+    {
+        self.inner.extend_from_within(src)
+    }
+
+    /// Like [`insert_many`](#method.insert_many), but clones the elements of a slice
+    ///
+    /// # Panics
+    /// Panics if `index > self.len()`
+    #[inline]
+    pub fn insert_slice(&mut self, index: usize, items: &[T]) {
+        self.insert_many(index, items.iter().cloned())
+    }
+
+    /// Returns a guard that derefs to the inner `Vec`, making sure `self` stays non-empty when it is dropped
+    ///
+    /// If the `Vec` is left empty, `self` is reset to contain only (a clone of) its original first element.
+    /// See [`NonEmptyVecGuard`](struct.NonEmptyVecGuard.html) for details
+    #[inline]
+    pub fn as_vec_mut(&mut self) -> NonEmptyVecGuard<'_, T> {
+        let placeholder = vec![self.first().clone()];
+        NonEmptyVecGuard::new(&mut self.inner, placeholder)
+    }
+
+    /// Like [`as_vec_mut`](#method.as_vec_mut), but scoped to `f`, and reports whether the `Vec` was left empty
+    ///
+    /// In that case, `self` is reset the same way and the result of `f` is returned inside the error
+    #[inline]
+    pub fn edit<F, R>(&mut self, f: F) -> Result<R, EmptyError<R>>
+    where
+        F: FnOnce(&mut Vec<T>) -> R,
+    {
+        let mut guard = self.as_vec_mut();
+        let res = f(&mut guard);
+        let emptied = guard.is_empty();
+        drop(guard);
+        if emptied {
+            Err(EmptyError(res))
+        } else {
+            Ok(res)
+        }
+    }
+
+    /// Like the slice method, but takes a `NonZeroUsize` and returns a `NonEmptyVec`
+    ///
+    /// # Panics
+    /// Panics if the capacity would overflow
+    #[inline]
+    pub fn repeat(&self, n: NonZeroUsize) -> Self {
+        let len = self
+            .inner
+            .len()
+            .checked_mul(n.get())
+            .expect("capacity overflow");
+        let mut inner = Vec::with_capacity(len);
+        for _ in 0..n.get() {
+            inner.extend_from_slice(&self.inner);
+        }
+        NonEmtpyVec { inner }
+    }
+
+    /// Pairs up every element of `self` with every element of `other`.
+    /// The pairs are ordered by the position of their element of `self` first
+    #[inline]
+    pub fn cartesian_product<U: Clone>(self, other: &NonEmtpyVec<U>) -> NonEmtpyVec<(T, U)> {
+        let mut inner = Vec::with_capacity(self.inner.len() * other.inner.len());
+        for x in self.inner {
+            inner.extend(other.inner.iter().map(|y| (x.clone(), y.clone())));
+        }
+        NonEmtpyVec { inner }
+    }
+
+    /// Places a clone of `sep` between every two adjacent elements
+    #[inline]
+    pub fn intersperse(self, sep: T) -> Self {
+        self.intersperse_with(|| sep.clone())
+    }
+
+    /// Like [`cycle`](#method.cycle), but consumes `self` and yields owned elements
+    #[inline]
+    pub fn into_cycle(self) -> Cycle<std::vec::IntoIter<T>> {
+        self.inner.into_iter().cycle()
+    }
+}
+
+impl_any_alloc! {
+    impl<T: PartialEq> NonEmtpyVec<T> {
+        copy_fn!(pub fn dedup(&mut self););
+    }
+}
+
+impl<T> NonEmtpyVec<T>
+where
+    T: PartialEq<T>,
+{
+    /// Collapses consecutive equal elements into `(count, element)` pairs (run-length encoding)
+    #[inline]
+    pub fn dedup_with_count(self) -> NonEmtpyVec<(NonZeroUsize, T)> {
+        let mut inner: Vec<(NonZeroUsize, T)> = Vec::new();
+        for x in self.inner {
+            match inner.last_mut() {
+                Some((n, y)) if *y == x => *n = n.saturating_add(1),
+                _ => inner.push((NonZeroUsize::MIN, x)),
+            }
+        }
+        NonEmtpyVec { inner }
+    }
+
+    /// Like [`dedup_with_count`](#method.dedup_with_count), but borrows the elements and works lazily
+    #[inline]
+    pub fn iter_dedup_with_count(&self) -> impl Iterator<Item = (NonZeroUsize, &T)> + '_ {
+        self.inner.chunk_by(|a, b| a == b).map(|run| {
+            // this is ok since `chunk_by` never yields empty slices
+            (unsafe { NonZeroUsize::new_unchecked(run.len()) }, &run[0])
+        })
+    }
+}
+
+impl<T> NonEmtpyVec<T>
+where
+    T: Ord,
+{
+    /// Like [`Iterator::min`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.min), but doesn't need to return an `Option`
+    #[inline]
+    pub fn min_elem(&self) -> &T {
+        let (first, rest) = self.split_first();
+        rest.iter().fold(first, cmp::min)
+    }
+
+    /// Like [`Iterator::max`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.max), but doesn't need to return an `Option`
     #[inline]
-    pub fn capacity(&self) -> NonZeroUsize {
-        // this is ok since it relies on the safety guarantee that there is always at least one element
-        unsafe { NonZeroUsize::new_unchecked(self.inner.capacity()) }
+    pub fn max_elem(&self) -> &T {
+        let (first, rest) = self.split_first();
+        rest.iter().fold(first, cmp::max)
     }
 
-    copy_fn! {
-        pub fn reserve(&mut self, additional: usize);
-        pub fn reserve_exact(&mut self, additional: usize);
-        pub fn shrink_to_fit(&mut self);
-        pub fn into_boxed_slice(self) -> Box<[T]>;
+    /// Inserts `value` into the already sorted `self` so that it stays sorted, and returns the index it was inserted at
+    ///
+    /// If there are elements equal to `value`, it is inserted after them
+    #[inline]
+    pub fn insert_sorted(&mut self, value: T) -> usize {
+        let index = self.inner.partition_point(|x| *x <= value);
+        self.inner.insert(index, value);
+        index
     }
 
+    /// Sorts `self` and then removes consecutive duplicates
+    ///
+    /// There is always at least one element left
     #[inline]
-    pub fn truncate(&mut self, len: NonZeroUsize) {
-        self.inner.truncate(len.get())
+    pub fn sort_and_dedup(&mut self) {
+        self.inner.sort();
+        self.inner.dedup();
     }
 
-    copy_fn! {
-        pub fn as_slice(&self) -> &[T];
-        pub fn as_mut_slice(&mut self) -> &mut [T];
-        pub fn as_ptr(&self) -> *const T;
-        pub fn as_mut_ptr(&mut self) -> *mut T;
+    /// Merges two sorted vectors into one sorted vector
+    ///
+    /// For equal elements, those from `self` come first
+    #[inline]
+    pub fn merge_sorted(self, other: Self) -> Self {
+        self.merge_sorted_by(other, Ord::cmp)
     }
 
-    /// Works like [`set_len`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.set_len) with one slight change
-    ///
-    /// # Safety
-    /// This is (additionally to the constraints of [`set_len`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.set_len)) only safe when `new_len > 0`
+    /// Returns `(self.min_elem(), self.max_elem())`, but only traverses `self` once
     #[inline]
-    pub unsafe fn set_len(&mut self, new_len: usize) {
-        self.inner.set_len(new_len)
+    pub fn minmax(&self) -> (&T, &T) {
+        let (first, rest) = self.split_first();
+        rest.iter().fold((first, first), |(min, max), x| {
+            (cmp::min(min, x), cmp::max(max, x))
+        })
     }
 
-    /// Works like [`swap_remove`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.swap_remove) with one slight change
-    ///
-    /// # Panics
-    /// Panics if `self.len() == 1`
+    /// Returns the index of the minimum (the first one if there are several)
     #[inline]
-    pub fn swap_remove(&mut self, index: usize) -> T {
-        assert!(
-            self.len() >= 1,
-            "tried to remove the last item of NonEmptyVec"
-        );
-        self.inner.swap_remove(index)
+    pub fn position_min(&self) -> usize {
+        let (first, rest) = self.split_first();
+        rest.iter()
+            .enumerate()
+            .map(|(i, x)| (x, i + 1))
+            .fold((first, 0), |min, x| if x.0 < min.0 { x } else { min })
+            .1
     }
 
-    copy_fn! {
-        pub fn insert(&mut self, index: usize, element: T);
+    /// Returns the index of the maximum (the last one if there are several)
+    #[inline]
+    pub fn position_max(&self) -> usize {
+        let (first, rest) = self.split_first();
+        rest.iter()
+            .enumerate()
+            .map(|(i, x)| (x, i + 1))
+            .fold((first, 0), |max, x| if x.0 < max.0 { max } else { x })
+            .1
     }
 
-    /// Works like [`remove`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.remove) with one slight change
+    /// Keeps only the `k` smallest elements, sorted in ascending order
     ///
-    /// # Panics
-    /// Panics if `self.len() == 1`
+    /// This doesn't sort the whole `NonEmptyVec`, but uses [`select_nth_unstable`](https://doc.rust-lang.org/std/primitive.slice.html#method.select_nth_unstable)
     #[inline]
-    pub fn remove(&mut self, index: usize) -> T {
-        assert!(
-            self.len() >= 1,
-            "tried to remove the last item of NonEmptyVec"
-        );
-        self.inner.remove(index)
+    pub fn k_smallest(mut self, k: NonZeroUsize) -> Self {
+        let k = k.get();
+        if k < self.inner.len() {
+            self.inner.select_nth_unstable(k - 1);
+            self.inner.truncate(k);
+        }
+        self.inner.sort_unstable();
+        self
+    }
+
+    /// Keeps only the `k` largest elements, sorted in descending order
+    ///
+    /// This doesn't sort the whole `NonEmptyVec`, but uses [`select_nth_unstable_by`](https://doc.rust-lang.org/std/primitive.slice.html#method.select_nth_unstable_by)
+    #[inline]
+    pub fn k_largest(mut self, k: NonZeroUsize) -> Self {
+        let k = k.get();
+        if k < self.inner.len() {
+            self.inner.select_nth_unstable_by(k - 1, |a, b| b.cmp(a));
+            self.inner.truncate(k);
+        }
+        self.inner.sort_unstable_by(|a, b| b.cmp(a));
+        self
     }
+}
 
-    /// Works like [`retain`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.retain) with one slight change
+impl<T> NonEmtpyVec<T> {
+    /// Works like [`splice`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.splice) with one slight change
+    ///
+    /// Returns an error (and leaves `self` untouched) if `range` covers every element and `replace_with` is empty.
+    /// In that case, the first element of `replace_with` is taken immediately
     ///
     /// # Panics
-    /// Panics if at the end there are no more items left
+    /// Panics if `range` is out of bounds
     #[inline]
-    pub fn retain<F>(&mut self, f: F)
+    pub fn try_splice<R, I>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Result<Splice<'_, I::IntoIter>, EmptyError>
     where
-        F: FnMut(&T) -> bool,
+        I: IntoIterator<Item = T>,
+        R: RangeBounds<usize>,
     {
-        self.inner.retain(f);
-        assert!(
-            !self.inner.is_empty(),
-            "no items left after NonEmptyVec::retain"
-        )
+        let range = to_range(range, self.inner.len());
+        let mut replace_with = replace_with.into_iter();
+        let mut idx = range.start;
+        if range.end - range.start == self.inner.len() {
+            // make sure there is always one element left
+            match replace_with.next() {
+                Some(first) => self.inner.push(first),
+                None => return Err(EmptyError(())),
+            }
+            idx += 1;
+        }
+        let drain = Drain::new(&mut self.inner, range);
+        Ok(Splice::new(drain, replace_with, idx))
     }
+}
 
+impl<V> NonEmtpyVec<V> {
+    /// Flattens a `NonEmptyVec` of non-empty slices into a single `NonEmptyVec`.
+    /// Works like [`concat`](https://doc.rust-lang.org/std/primitive.slice.html#method.concat), but keeps the guarantee that there is at least one element
     #[inline]
-    pub fn dedup_by_key<F, K>(&mut self, key: F)
+    pub fn concat_nonempty<T: Clone>(&self) -> NonEmtpyVec<T>
     where
-        F: FnMut(&mut T) -> K,
-        K: PartialEq<K>,
+        V: Borrow<NonEmptySlice<T>>,
     {
-        self.inner.dedup_by_key(key);
+        self.join_nonempty(&[])
     }
 
+    /// Flattens a `NonEmptyVec` of non-empty slices into a single `NonEmptyVec`, placing `sep` between each of them.
+    /// Works like [`join`](https://doc.rust-lang.org/std/primitive.slice.html#method.join), but keeps the guarantee that there is at least one element
     #[inline]
-    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    pub fn join_nonempty<T: Clone>(&self, sep: &[T]) -> NonEmtpyVec<T>
     where
-        F: FnMut(&mut T, &mut T) -> bool,
+        V: Borrow<NonEmptySlice<T>>,
     {
-        self.inner.dedup_by(same_bucket)
+        let len = self
+            .inner
+            .iter()
+            .map(|v| Borrow::<NonEmptySlice<T>>::borrow(v).len())
+            .sum::<usize>()
+            + sep.len() * (self.inner.len() - 1);
+        let mut inner = Vec::with_capacity(len);
+        let (first, rest) = self.split_first();
+        inner.extend_from_slice(Borrow::<NonEmptySlice<T>>::borrow(first));
+        for v in rest {
+            inner.extend_from_slice(sep);
+            inner.extend_from_slice(Borrow::<NonEmptySlice<T>>::borrow(v));
+        }
+        NonEmtpyVec { inner }
     }
+}
 
-    copy_fn!(pub fn push(&mut self, value: T););
+impl<T> NonEmtpyVec<NonEmtpyVec<T>> {
+    /// Gets rid of one level of nested `NonEmptyVec`s. Works like [`Iterator::flatten`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.flatten).
+    ///
+    /// The exact length of the result is allocated up front
+    #[inline]
+    pub fn flatten(self) -> NonEmtpyVec<T> {
+        let len = self.inner.iter().map(|v| v.inner.len()).sum();
+        let mut inner = Vec::with_capacity(len);
+        for mut v in self.inner {
+            inner.append(&mut v.inner);
+        }
+        NonEmtpyVec { inner }
+    }
 
-    /// Will not pop the last item, instead returns `None`
+    /// Turns the rows of `self` into columns and vice versa
+    ///
+    /// Returns an error (containing `self`) if the rows don't all have the same length
     #[inline]
-    pub fn pop(&mut self) -> Option<T> {
-        if self.inner.len() < 2 {
-            None
-        } else {
-            self.inner.pop()
+    pub fn transpose(self) -> Result<NonEmtpyVec<NonEmtpyVec<T>>, ShapeError<Self>> {
+        let width = self.first().inner.len();
+        if self.inner.iter().any(|row| row.inner.len() != width) {
+            return Err(ShapeError(self));
         }
+        let height = self.inner.len();
+        let mut columns: Vec<_> = (0..width).map(|_| Vec::with_capacity(height)).collect();
+        for row in self.inner {
+            for (column, x) in columns.iter_mut().zip(row.inner) {
+                column.push(x);
+            }
+        }
+        Ok(NonEmtpyVec {
+            inner: columns
+                .into_iter()
+                .map(|inner| NonEmtpyVec { inner })
+                .collect(),
+        })
     }
+}
 
+impl<A, B> NonEmtpyVec<(A, B)> {
+    /// Splits a `NonEmptyVec` of pairs into two `NonEmptyVec`s.
+    /// Works like [`Iterator::unzip`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.unzip)
     #[inline]
-    pub fn append(&mut self, other: &mut Self) {
-        self.inner.append(&mut other.inner)
+    pub fn unzip(self) -> (NonEmtpyVec<A>, NonEmtpyVec<B>) {
+        let (a, b) = self.inner.into_iter().unzip();
+        (NonEmtpyVec { inner: a }, NonEmtpyVec { inner: b })
     }
+}
 
+impl<T> AsRef<[T]> for NonEmtpyVec<T> {
     #[inline]
-    pub fn append_vec(&mut self, other: &mut Vec<T>) {
-        self.inner.append(other)
+    fn as_ref(&self) -> &[T] {
+        self
     }
+}
 
-    copy_fn!(pub fn len(&self) -> usize;);
+impl<T> AsRef<Vec<T>> for NonEmtpyVec<T> {
+    #[inline]
+    fn as_ref(&self) -> &Vec<T> {
+        &self.inner
+    }
+}
 
+impl<T> AsRef<NonEmtpyVec<T>> for NonEmtpyVec<T> {
     #[inline]
-    pub fn split_off(&mut self, at: NonZeroUsize) -> Vec<T> {
-        self.inner.split_off(at.get())
+    fn as_ref(&self) -> &NonEmtpyVec<T> {
+        self
     }
+}
 
+impl<T> AsMut<[T]> for NonEmtpyVec<T> {
     #[inline]
-    pub fn resize_with<F>(&mut self, new_len: NonZeroUsize, f: F)
-    where
-        F: FnMut() -> T,
-    {
-        self.inner.resize_with(new_len.get(), f)
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut *self
     }
+}
 
-    /// Creates a new `NonEmptyVec` by applying `f` to every element of `self`
+impl<T> AsMut<NonEmtpyVec<T>> for NonEmtpyVec<T> {
     #[inline]
-    pub fn map<F: FnMut(T) -> U, U>(self, f: F) -> NonEmtpyVec<U> {
-        NonEmtpyVec {
-            inner: self.inner.into_iter().map(f).collect(),
-        }
+    fn as_mut(&mut self) -> &mut NonEmtpyVec<T> {
+        self
     }
+}
 
-    /// Applies `f` to every element of `self`
+impl<T> Borrow<[T]> for NonEmtpyVec<T> {
     #[inline]
-    pub fn map_in_place<F: FnMut(T) -> T>(&mut self, f: F) {
-        unsafe {
-            std::ptr::write(
-                &mut self.inner,
-                std::ptr::read(&self.inner).into_iter().map(f).collect(),
-            )
-        }
+    fn borrow(&self) -> &[T] {
+        self
     }
+}
 
-    /// Works like `.map(f).flatten()`
+impl<T> BorrowMut<[T]> for NonEmtpyVec<T> {
     #[inline]
-    pub fn flat_map<I: IntoIterator, F: FnMut(T) -> I>(self, f: F) -> NonEmtpyVec<I::Item> {
-        NonEmtpyVec {
-            inner: self.inner.into_iter().flat_map(f).collect(),
-        }
+    fn borrow_mut(&mut self) -> &mut [T] {
+        &mut *self
     }
+}
 
-    /// Creates a `NonEmptyVec` whose elements are references to this `NonEmptyVec`'s elements
+impl<T> Borrow<NonEmptySlice<T>> for NonEmtpyVec<T> {
     #[inline]
-    pub fn new_ref(&self) -> NonEmtpyVec<&T> {
-        NonEmtpyVec {
-            inner: self.inner.iter().collect(),
+    fn borrow(&self) -> &NonEmptySlice<T> {
+        self.as_nonempty_slice()
+    }
+}
+
+impl<T> BorrowMut<NonEmptySlice<T>> for NonEmtpyVec<T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut NonEmptySlice<T> {
+        self.as_mut_nonempty_slice()
+    }
+}
+
+impl<T: Default> Default for NonEmtpyVec<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+/// Fails to compile if `N == 0`
+impl<T, const N: usize> From<[T; N]> for NonEmtpyVec<T> {
+    #[inline]
+    fn from(arr: [T; N]) -> Self {
+        const { assert!(N > 0, "cannot create a NonEmptyVec from an empty array") };
+        Self {
+            inner: Vec::from(arr),
         }
     }
+}
 
-    /// Creates a `NonEmptyVec` whose elements are mutable references to this `NonEmptyVec`'s elements
+/// Fails to compile if `N == 0`
+impl<T: Clone, const N: usize> From<&[T; N]> for NonEmtpyVec<T> {
     #[inline]
-    pub fn new_mut(&mut self) -> NonEmtpyVec<&mut T> {
-        NonEmtpyVec {
-            inner: self.inner.iter_mut().collect(),
+    fn from(arr: &[T; N]) -> Self {
+        const { assert!(N > 0, "cannot create a NonEmptyVec from an empty array") };
+        Self {
+            inner: arr.to_vec(),
         }
     }
 }
 
-impl<T> NonEmtpyVec<T>
-where
-    T: Clone,
-{
+#[cfg(not(feature = "allocator_api"))]
+impl<T> From<NonEmtpyVec<T>> for Vec<T> {
     #[inline]
-    pub fn resize(&mut self, new_len: NonZeroUsize, value: T) {
-        self.inner.resize(new_len.get(), value)
+    fn from(v: NonEmtpyVec<T>) -> Self {
+        v.inner
     }
+}
 
-    copy_fn!(pub fn extend_from_slice(&mut self, other: &[T]););
+impl<T> From<NonEmtpyVec<T>> for Box<[T]> {
+    #[inline]
+    fn from(v: NonEmtpyVec<T>) -> Self {
+        v.into_boxed_slice()
+    }
 }
 
-impl<T> NonEmtpyVec<T>
-where
-    T: PartialEq<T>,
-{
-    copy_fn!(pub fn dedup(&mut self););
+impl<T> From<NonEmtpyVec<T>> for Rc<[T]> {
+    #[inline]
+    fn from(v: NonEmtpyVec<T>) -> Self {
+        Rc::from(v.inner)
+    }
 }
 
-impl<T> NonEmtpyVec<T> {
+impl<T> From<NonEmtpyVec<T>> for Arc<[T]> {
     #[inline]
-    pub fn splice<R, I>(
-        &mut self,
-        range: R,
-        replace_with: I,
-    ) -> Splice<<I as IntoIterator>::IntoIter>
-    where
-        I: IntoIterator<Item = T>,
-        R: RangeBounds<usize>,
-    {
-        self.inner.splice(range, replace_with)
+    fn from(v: NonEmtpyVec<T>) -> Self {
+        Arc::from(v.inner)
     }
 }
 
-impl<T, I: IntoIterator<Item = T>> NonEmtpyVec<I> {
-    /// Gets rid of one level of nested collections. Works like [`Iterator::flatten`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.flatten).
+impl<'a, T: Clone> From<NonEmtpyVec<T>> for Cow<'a, [T]> {
     #[inline]
-    pub fn flatten(self) -> NonEmtpyVec<T> {
-        NonEmtpyVec {
-            inner: self.inner.into_iter().flatten().collect(),
-        }
+    fn from(v: NonEmtpyVec<T>) -> Self {
+        Cow::Owned(v.inner)
     }
 }
 
-impl<T> AsRef<[T]> for NonEmtpyVec<T> {
+impl<T> From<NonEmtpyVec<T>> for VecDeque<T> {
     #[inline]
-    fn as_ref(&self) -> &[T] {
-        &*self
+    fn from(v: NonEmtpyVec<T>) -> Self {
+        VecDeque::from(v.inner)
     }
 }
 
-impl<T> AsRef<Vec<T>> for NonEmtpyVec<T> {
+impl<T: Ord> From<NonEmtpyVec<T>> for BinaryHeap<T> {
     #[inline]
-    fn as_ref(&self) -> &Vec<T> {
-        &self.inner
+    fn from(v: NonEmtpyVec<T>) -> Self {
+        BinaryHeap::from(v.inner)
     }
 }
 
-impl<T> AsRef<NonEmtpyVec<T>> for NonEmtpyVec<T> {
+impl From<NonEmtpyVec<char>> for String {
     #[inline]
-    fn as_ref(&self) -> &NonEmtpyVec<T> {
-        self
+    fn from(v: NonEmtpyVec<char>) -> Self {
+        v.inner.into_iter().collect()
     }
 }
 
-impl<T> AsMut<[T]> for NonEmtpyVec<T> {
+impl<T> From<(T, Vec<T>)> for NonEmtpyVec<T> {
     #[inline]
-    fn as_mut(&mut self) -> &mut [T] {
-        &mut *self
+    fn from((head, tail): (T, Vec<T>)) -> Self {
+        Self::from_head_and_tail(head, tail)
     }
 }
 
-impl<T> AsMut<Vec<T>> for NonEmtpyVec<T> {
+#[cfg(not(feature = "allocator_api"))]
+impl<T> TryFrom<Vec<T>> for NonEmtpyVec<T> {
+    type Error = EmptyError<Vec<T>>;
+
     #[inline]
-    fn as_mut(&mut self) -> &mut Vec<T> {
-        &mut self.inner
+    fn try_from(v: Vec<T>) -> Result<Self, Self::Error> {
+        if v.is_empty() {
+            Err(EmptyError(v))
+        } else {
+            Ok(Self { inner: v })
+        }
     }
 }
 
-impl<T> AsMut<NonEmtpyVec<T>> for NonEmtpyVec<T> {
+impl<T> TryFrom<VecDeque<T>> for NonEmtpyVec<T> {
+    type Error = EmptyError<VecDeque<T>>;
+
     #[inline]
-    fn as_mut(&mut self) -> &mut NonEmtpyVec<T> {
-        self
+    fn try_from(v: VecDeque<T>) -> Result<Self, Self::Error> {
+        if v.is_empty() {
+            Err(EmptyError(v))
+        } else {
+            Ok(Self { inner: v.into() })
+        }
     }
 }
 
-impl<T> Borrow<[T]> for NonEmtpyVec<T> {
+impl<T> TryFrom<BinaryHeap<T>> for NonEmtpyVec<T> {
+    type Error = EmptyError<BinaryHeap<T>>;
+
     #[inline]
-    fn borrow(&self) -> &[T] {
-        &*self
+    fn try_from(h: BinaryHeap<T>) -> Result<Self, Self::Error> {
+        if h.is_empty() {
+            Err(EmptyError(h))
+        } else {
+            Ok(Self { inner: h.into() })
+        }
     }
 }
 
-impl<T> BorrowMut<[T]> for NonEmtpyVec<T> {
+impl<T, S> TryFrom<HashSet<T, S>> for NonEmtpyVec<T> {
+    type Error = EmptyError<HashSet<T, S>>;
+
     #[inline]
-    fn borrow_mut(&mut self) -> &mut [T] {
-        &mut *self
+    fn try_from(s: HashSet<T, S>) -> Result<Self, Self::Error> {
+        if s.is_empty() {
+            Err(EmptyError(s))
+        } else {
+            Ok(Self {
+                inner: s.into_iter().collect(),
+            })
+        }
     }
 }
 
-impl<T: Default> Default for NonEmtpyVec<T> {
+impl<T> TryFrom<BTreeSet<T>> for NonEmtpyVec<T> {
+    type Error = EmptyError<BTreeSet<T>>;
+
     #[inline]
-    fn default() -> Self {
-        Self::new(T::default())
+    fn try_from(s: BTreeSet<T>) -> Result<Self, Self::Error> {
+        if s.is_empty() {
+            Err(EmptyError(s))
+        } else {
+            Ok(Self {
+                inner: s.into_iter().collect(),
+            })
+        }
+    }
+}
+
+impl<'a, T: Clone> TryFrom<&'a [T]> for NonEmtpyVec<T> {
+    type Error = EmptyError<&'a [T]>;
+
+    #[inline]
+    fn try_from(s: &'a [T]) -> Result<Self, Self::Error> {
+        Self::from_slice(s).ok_or(EmptyError(s))
+    }
+}
+
+impl<'a> TryFrom<&'a str> for NonEmtpyVec<char> {
+    type Error = EmptyError<&'a str>;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        if s.is_empty() {
+            Err(EmptyError(s))
+        } else {
+            Ok(Self {
+                inner: s.chars().collect(),
+            })
+        }
     }
 }
 
+#[cfg(not(feature = "allocator_api"))]
 impl<'a, T> Extend<&'a T> for NonEmtpyVec<T>
 where
     T: 'a + Copy,
@@ -391,6 +2106,7 @@ where
     }
 }
 
+#[cfg(not(feature = "allocator_api"))]
 impl<T> Extend<T> for NonEmtpyVec<T> {
     #[inline]
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
@@ -398,6 +2114,7 @@ impl<T> Extend<T> for NonEmtpyVec<T> {
     }
 }
 
+#[cfg(not(feature = "allocator_api"))]
 impl<T> IntoIterator for NonEmtpyVec<T> {
     type Item = T;
     type IntoIter = std::vec::IntoIter<T>;
@@ -408,6 +2125,7 @@ impl<T> IntoIterator for NonEmtpyVec<T> {
     }
 }
 
+#[cfg(not(feature = "allocator_api"))]
 impl<'a, T> IntoIterator for &'a NonEmtpyVec<T> {
     type Item = &'a T;
     type IntoIter = std::slice::Iter<'a, T>;
@@ -418,6 +2136,7 @@ impl<'a, T> IntoIterator for &'a NonEmtpyVec<T> {
     }
 }
 
+#[cfg(not(feature = "allocator_api"))]
 impl<'a, T> IntoIterator for &'a mut NonEmtpyVec<T> {
     type Item = &'a mut T;
     type IntoIter = std::slice::IterMut<'a, T>;
@@ -440,6 +2159,75 @@ where
     }
 }
 
+impl<T, I> IndexMut<I> for NonEmtpyVec<T>
+where
+    I: SliceIndex<[T]>,
+{
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        self.inner.index_mut(index)
+    }
+}
+
+macro_rules! impl_slice_eq {
+    ([$($vars:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<T, U, $($vars)*> PartialEq<$rhs> for $lhs
+        where
+            T: PartialEq<U>,
+        {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool {
+                self[..] == other[..]
+            }
+        }
+    };
+}
+
+impl_slice_eq! { [] NonEmtpyVec<T>, Vec<U> }
+impl_slice_eq! { [] NonEmtpyVec<T>, [U] }
+impl_slice_eq! { [] NonEmtpyVec<T>, &[U] }
+impl_slice_eq! { [] NonEmtpyVec<T>, &mut [U] }
+impl_slice_eq! { [const N: usize] NonEmtpyVec<T>, [U; N] }
+impl_slice_eq! { [const N: usize] NonEmtpyVec<T>, &[U; N] }
+impl_slice_eq! { [] NonEmtpyVec<T>, NonEmptySlice<U> }
+impl_slice_eq! { [] Vec<T>, NonEmtpyVec<U> }
+impl_slice_eq! { [] [T], NonEmtpyVec<U> }
+impl_slice_eq! { [] &[T], NonEmtpyVec<U> }
+impl_slice_eq! { [] &mut [T], NonEmtpyVec<U> }
+impl_slice_eq! { [const N: usize] [T; N], NonEmtpyVec<U> }
+impl_slice_eq! { [] NonEmptySlice<T>, NonEmtpyVec<U> }
+
+impl NonEmtpyVec<u8> {
+    /// Constructs a new `NonEmptyVec<u8>` from the bytes of a `String` (returns `None` iff the `String` is empty)
+    #[inline]
+    pub fn from_string(s: String) -> Option<Self> {
+        Self::from_vec(s.into_bytes())
+    }
+
+    /// Converts `self` into a `String` if it is valid UTF-8
+    ///
+    /// Works like [`String::from_utf8`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8)
+    #[inline]
+    pub fn into_string(self) -> Result<String, FromUtf8Error> {
+        String::from_utf8(self.inner)
+    }
+
+    /// Constructs a new `NonEmptyVec<u8>` from the bytes of a `CString`, without the trailing nul byte
+    /// (returns `None` iff the `CString` is empty)
+    #[inline]
+    pub fn from_cstring(s: CString) -> Option<Self> {
+        Self::from_vec(s.into_bytes())
+    }
+
+    /// Converts `self` into a `CString` if it contains no nul bytes
+    ///
+    /// Works like [`CString::new`](https://doc.rust-lang.org/std/ffi/struct.CString.html#method.new)
+    #[inline]
+    pub fn try_into_cstring(self) -> Result<CString, NulError> {
+        CString::new(self.inner)
+    }
+}
+
 impl Write for NonEmtpyVec<u8> {
     #[inline]
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
@@ -462,6 +2250,10 @@ impl Write for NonEmtpyVec<u8> {
     }
 }
 
+/// Like [`FromIterator`](https://doc.rust-lang.org/std/iter/trait.FromIterator.html), but fallible
+///
+/// Note that `impl<T> FromIterator<T> for Option<NonEmptyVec<T>>` is not possible due to the orphan rules,
+/// so `iter.collect::<Option<NonEmptyVec<_>>>()` can't work. Use [`TryCollect::try_collect`](trait.TryCollect.html#method.try_collect) instead
 pub trait TryFromIterator<T>: Sized {
     fn try_from_iter<I: Iterator<Item = T>>(iter: I) -> Option<Self>;
 }
@@ -473,10 +2265,112 @@ impl<T> TryFromIterator<T> for NonEmtpyVec<T> {
     }
 }
 
+/// Returns `None` iff the iterator is empty
+impl<T> TryFromIterator<T> for Box<[T]> {
+    fn try_from_iter<I: Iterator<Item = T>>(iter: I) -> Option<Self> {
+        NonEmtpyVec::try_from_iter(iter).map(Self::from)
+    }
+}
+
+/// Returns `None` iff the iterator is empty
+impl<T> TryFromIterator<T> for Box<NonEmptySlice<T>> {
+    fn try_from_iter<I: Iterator<Item = T>>(iter: I) -> Option<Self> {
+        NonEmtpyVec::try_from_iter(iter).map(NonEmtpyVec::into_boxed_nonempty_slice)
+    }
+}
+
+/// Returns `None` iff the iterator is empty
+impl<T> TryFromIterator<T> for Rc<[T]> {
+    fn try_from_iter<I: Iterator<Item = T>>(iter: I) -> Option<Self> {
+        NonEmtpyVec::try_from_iter(iter).map(Self::from)
+    }
+}
+
+/// Returns `None` iff the iterator is empty
+impl<T> TryFromIterator<T> for Arc<[T]> {
+    fn try_from_iter<I: Iterator<Item = T>>(iter: I) -> Option<Self> {
+        NonEmtpyVec::try_from_iter(iter).map(Self::from)
+    }
+}
+
 pub trait TryCollect: Iterator + Sized {
     fn try_collect<E: TryFromIterator<Self::Item>>(self) -> Option<E> {
         E::try_from_iter(self)
     }
+
+    /// Like [`try_collect`](#method.try_collect), but returns an error instead of `None`
+    fn try_collect_result<E: TryFromIterator<Self::Item>>(self) -> Result<E, EmptyError> {
+        E::try_from_iter(self).ok_or(EmptyError(()))
+    }
 }
 
 impl<I: Iterator> TryCollect for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic::{self, AssertUnwindSafe};
+
+    #[test]
+    fn map_in_place_reuses_allocation() {
+        let mut v = ne_vec![String::from("a"), "b".into(), "c".into()];
+        let ptr = v.as_ptr();
+        v.map_in_place(|s| s + "!");
+        assert_eq!(v, ["a!", "b!", "c!"]);
+        assert_eq!(v.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn map_in_place_panic_closes_hole() {
+        for panic_at in 0..3 {
+            let rc = Rc::new(());
+            let mut v = NonEmtpyVec::from_elem(rc.clone(), NonZeroUsize::new(3).unwrap());
+            let mut calls = 0;
+            let res = panic::catch_unwind(AssertUnwindSafe(|| {
+                v.map_in_place(|x| {
+                    calls += 1;
+                    if calls > panic_at {
+                        panic!();
+                    }
+                    x
+                })
+            }));
+            assert!(res.is_err());
+            assert_eq!(v.len(), 2);
+            // the element `f` panicked on was dropped exactly once
+            assert_eq!(Rc::strong_count(&rc), 3);
+            drop(v);
+            assert_eq!(Rc::strong_count(&rc), 1);
+        }
+    }
+
+    #[test]
+    fn edit_restores_first_when_emptied() {
+        let mut v = ne_vec![1, 2, 3];
+        assert_eq!(v.edit(|v| v.pop()), Ok(Some(3)));
+        assert_eq!(v, [1, 2]);
+        assert_eq!(v.edit(Vec::clear), Err(EmptyError(())));
+        assert_eq!(v, [1]);
+    }
+
+    #[test]
+    fn edit_panic_keeps_invariant() {
+        let mut v = ne_vec![1, 2, 3];
+        let res = panic::catch_unwind(AssertUnwindSafe(|| {
+            v.edit(|v| {
+                v.push(4);
+                panic!()
+            })
+        }));
+        assert!(res.is_err());
+        assert_eq!(v, [1, 2, 3, 4]);
+        let res = panic::catch_unwind(AssertUnwindSafe(|| {
+            v.edit(|v| {
+                v.clear();
+                panic!()
+            })
+        }));
+        assert!(res.is_err());
+        assert_eq!(v, [1]);
+    }
+}
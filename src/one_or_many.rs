@@ -0,0 +1,97 @@
+//! (De)serializes a `NonEmptyVec<T>` as either a single value or a sequence of at least one, via the `serde` feature
+//!
+//! Use it with `#[serde(with = "nonempty_vec::one_or_many")]`.
+//! A `NonEmptyVec` with exactly one element is serialized as just that element.
+//! When deserializing, any sequence counts as "many", so `T` itself shouldn't be deserialized from a sequence.
+//! This requires a self-describing format (like JSON)
+
+use crate::NonEmtpyVec;
+use ::serde::de::{self, Deserializer};
+use ::serde::ser::{Serialize, Serializer};
+use ::serde::Deserialize;
+
+/// Serializes `v` as its only element if it has exactly one, otherwise as a sequence
+#[inline]
+pub fn serialize<T: Serialize, S: Serializer>(
+    v: &NonEmtpyVec<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match v.as_single() {
+        Some(x) => x.serialize(serializer),
+        None => v.serialize(serializer),
+    }
+}
+
+// the input is buffered before picking a variant, so `T` is always deserialized
+// with its own type hint (e.g. `deserialize_option`), not from whatever `deserialize_any` saw
+#[derive(Deserialize)]
+#[serde(
+    untagged,
+    expecting = "a single value or a sequence with at least one element"
+)]
+enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+/// Deserializes either a single `T` or a non-empty sequence of them
+#[inline]
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<NonEmtpyVec<T>, D::Error> {
+    match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::Many(inner) => NonEmtpyVec::from_vec(inner)
+            .ok_or_else(|| de::Error::invalid_length(0, &"at least one element")),
+        OneOrMany::One(x) => Ok(NonEmtpyVec::new(x)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::deserialize;
+    use crate::{ne_vec, NonEmtpyVec};
+    use ::serde::de::value::{Error, SeqDeserializer, StrDeserializer, U32Deserializer};
+    use ::serde::de::IntoDeserializer;
+
+    fn scalar<T: for<'de> ::serde::Deserialize<'de>>(x: u32) -> Result<NonEmtpyVec<T>, Error> {
+        let d: U32Deserializer<Error> = x.into_deserializer();
+        deserialize(d)
+    }
+
+    fn seq<T: for<'de> ::serde::Deserialize<'de>>(xs: Vec<u32>) -> Result<NonEmtpyVec<T>, Error> {
+        let d: SeqDeserializer<_, Error> = xs.into_deserializer();
+        deserialize(d)
+    }
+
+    #[test]
+    fn scalar_is_one() {
+        assert_eq!(scalar::<u32>(5).unwrap(), ne_vec![5]);
+        let d: StrDeserializer<Error> = "x".into_deserializer();
+        assert_eq!(
+            deserialize::<String, _>(d).unwrap(),
+            ne_vec!["x".to_string()]
+        );
+    }
+
+    #[test]
+    fn scalar_uses_type_hint() {
+        assert_eq!(scalar::<Option<u32>>(5).unwrap(), ne_vec![Some(5)]);
+        assert_eq!(scalar::<u64>(5).unwrap(), ne_vec![5]);
+    }
+
+    #[test]
+    fn sequence_is_many() {
+        assert_eq!(seq::<u32>(vec![1, 2, 3]).unwrap(), ne_vec![1, 2, 3]);
+        assert_eq!(
+            seq::<Option<u32>>(vec![1, 2]).unwrap(),
+            ne_vec![Some(1), Some(2)]
+        );
+        assert_eq!(seq::<u32>(vec![7]).unwrap(), ne_vec![7]);
+    }
+
+    #[test]
+    fn empty_sequence_is_rejected() {
+        assert!(seq::<u32>(Vec::new()).is_err());
+        assert!(seq::<Option<u32>>(Vec::new()).is_err());
+    }
+}
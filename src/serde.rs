@@ -0,0 +1,23 @@
+//! `Serialize` and `Deserialize` impls via the `serde` feature
+
+use crate::NonEmtpyVec;
+use ::serde::de::{Deserialize, Deserializer, Error};
+use ::serde::ser::{Serialize, Serializer};
+
+impl<T: Serialize> Serialize for NonEmtpyVec<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for NonEmtpyVec<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let inner = Vec::deserialize(deserializer)?;
+        if inner.is_empty() {
+            Err(D::Error::invalid_length(0, &"at least one element"))
+        } else {
+            Ok(Self { inner })
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use crate::{Chunks, Combinations, Permutations, RChunks, SplitInclusive, Windows};
+use std::num::NonZeroUsize;
+use std::ops::{Deref, DerefMut};
+
+/// Like [`[T]`](https://doc.rust-lang.org/std/primitive.slice.html) but guaranteed to have at least one element.
+///
+/// This is to `NonEmptyVec<T>` what `[T]` is to `Vec<T>`
+#[derive(Debug, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct NonEmptySlice<T> {
+    inner: [T],
+}
+
+impl<T> Deref for NonEmptySlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for NonEmptySlice<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.inner
+    }
+}
+
+impl<T> NonEmptySlice<T> {
+    /// Converts a slice into a `NonEmptySlice<T>` (returns `None` iff the slice is empty)
+    #[inline]
+    pub fn from_slice(s: &[T]) -> Option<&Self> {
+        if s.is_empty() {
+            None
+        } else {
+            // this is ok since we just checked that there is at least one element
+            Some(unsafe { Self::from_slice_unchecked(s) })
+        }
+    }
+
+    /// Converts a mutable slice into a `NonEmptySlice<T>` (returns `None` iff the slice is empty)
+    #[inline]
+    pub fn from_mut_slice(s: &mut [T]) -> Option<&mut Self> {
+        if s.is_empty() {
+            None
+        } else {
+            // this is ok since we just checked that there is at least one element
+            Some(unsafe { Self::from_mut_slice_unchecked(s) })
+        }
+    }
+
+    /// Converts a slice into a `NonEmptySlice<T>` without checking its length
+    ///
+    /// # Safety
+    /// This is only safe when `!s.is_empty()`
+    #[inline]
+    pub unsafe fn from_slice_unchecked(s: &[T]) -> &Self {
+        // this is ok since `NonEmptySlice<T>` is `repr(transparent)` over `[T]`
+        &*(s as *const [T] as *const Self)
+    }
+
+    /// Converts a mutable slice into a `NonEmptySlice<T>` without checking its length
+    ///
+    /// # Safety
+    /// This is only safe when `!s.is_empty()`
+    #[inline]
+    pub unsafe fn from_mut_slice_unchecked(s: &mut [T]) -> &mut Self {
+        // this is ok since `NonEmptySlice<T>` is `repr(transparent)` over `[T]`
+        &mut *(s as *mut [T] as *mut Self)
+    }
+
+    /// Like `len`, but as a `NonZeroUsize`
+    #[inline]
+    pub fn len_nonzero(&self) -> NonZeroUsize {
+        // this is ok since it relies on the safety guarantee that there is always at least one element
+        unsafe { NonZeroUsize::new_unchecked(self.inner.len()) }
+    }
+
+    /// Like the slice method, but doesn't need to return an `Option`
+    #[inline]
+    pub fn first(&self) -> &T {
+        &self.inner[0]
+    }
+
+    /// Like the slice method, but doesn't need to return an `Option`
+    #[inline]
+    pub fn last(&self) -> &T {
+        &self.inner[self.inner.len() - 1]
+    }
+
+    /// Like the slice method, but doesn't need to return an `Option`
+    #[inline]
+    pub fn first_mut(&mut self) -> &mut T {
+        &mut self.inner[0]
+    }
+
+    /// Like the slice method, but doesn't need to return an `Option`
+    #[inline]
+    pub fn last_mut(&mut self) -> &mut T {
+        let last = self.inner.len() - 1;
+        &mut self.inner[last]
+    }
+
+    /// Like the slice method, but doesn't need to return an `Option`
+    #[inline]
+    pub fn split_first(&self) -> (&T, &[T]) {
+        let (first, rest) = self.inner.split_at(1);
+        (&first[0], rest)
+    }
+
+    /// Like the slice method, but doesn't need to return an `Option`
+    #[inline]
+    pub fn split_first_mut(&mut self) -> (&mut T, &mut [T]) {
+        let (first, rest) = self.inner.split_at_mut(1);
+        (&mut first[0], rest)
+    }
+
+    /// Like the slice method, but doesn't need to return an `Option`
+    #[inline]
+    pub fn split_last(&self) -> (&T, &[T]) {
+        let (rest, last) = self.inner.split_at(self.inner.len() - 1);
+        (&last[0], rest)
+    }
+
+    /// Like the slice method, but doesn't need to return an `Option`
+    #[inline]
+    pub fn split_last_mut(&mut self) -> (&mut T, &mut [T]) {
+        let at = self.inner.len() - 1;
+        let (rest, last) = self.inner.split_at_mut(at);
+        (&mut last[0], rest)
+    }
+
+    /// Returns everything after the first element
+    #[inline]
+    pub fn tail(&self) -> &[T] {
+        &self.inner[1..]
+    }
+
+    /// Returns everything after the first element
+    #[inline]
+    pub fn tail_mut(&mut self) -> &mut [T] {
+        &mut self.inner[1..]
+    }
+
+    /// Returns everything before the last element
+    #[inline]
+    pub fn init(&self) -> &[T] {
+        &self.inner[..self.inner.len() - 1]
+    }
+
+    /// Returns the only element iff `self.len() == 1`
+    #[inline]
+    pub fn as_single(&self) -> Option<&T> {
+        if self.inner.len() == 1 {
+            Some(&self.inner[0])
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.inner
+    }
+
+    /// Like the slice method, but takes a `NonZeroUsize` and yields `NonEmptySlice`s
+    ///
+    /// There are no windows if `size` is greater than the length of `self`
+    #[inline]
+    pub fn windows(&self, size: NonZeroUsize) -> Windows<'_, T> {
+        Windows::new(self.inner.windows(size.get()))
+    }
+
+    /// Like the slice method, but takes a `NonZeroUsize` and yields `NonEmptySlice`s
+    ///
+    /// There is always at least one chunk
+    #[inline]
+    pub fn chunks(&self, size: NonZeroUsize) -> Chunks<'_, T> {
+        Chunks::new(self.inner.chunks(size.get()))
+    }
+
+    /// Like the slice method, but takes a `NonZeroUsize` and yields `NonEmptySlice`s
+    ///
+    /// There is always at least one chunk
+    #[inline]
+    pub fn rchunks(&self, size: NonZeroUsize) -> RChunks<'_, T> {
+        RChunks::new(self.inner.rchunks(size.get()))
+    }
+
+    /// Works like the slice method
+    ///
+    /// There is always at least one segment, but the segments themselves may be empty
+    #[inline]
+    pub fn split<F: FnMut(&T) -> bool>(&self, pred: F) -> std::slice::Split<'_, T, F> {
+        self.inner.split(pred)
+    }
+
+    /// Like the slice method, but yields `NonEmptySlice`s
+    ///
+    /// There is always at least one segment
+    #[inline]
+    pub fn split_inclusive<F: FnMut(&T) -> bool>(&self, pred: F) -> SplitInclusive<'_, T, F> {
+        SplitInclusive::new(self.inner.split_inclusive(pred))
+    }
+
+    /// Returns an iterator over all orderings of the elements
+    ///
+    /// There is always at least one permutation
+    #[inline]
+    pub fn permutations(&self) -> Permutations<'_, T> {
+        Permutations::new(&self.inner)
+    }
+
+    /// Returns an iterator over all selections of `k` elements
+    ///
+    /// There are no combinations if `k` is greater than the length of `self`
+    #[inline]
+    pub fn combinations(&self, k: NonZeroUsize) -> Combinations<'_, T> {
+        Combinations::new(&self.inner, k.get())
+    }
+}
+
+impl<T> AsRef<[T]> for NonEmptySlice<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.inner
+    }
+}
+
+impl<T> AsMut<[T]> for NonEmptySlice<T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.inner
+    }
+}
@@ -0,0 +1,27 @@
+//! Conversions from and to [`SmallVec`](https://docs.rs/smallvec/1/smallvec/struct.SmallVec.html) via the `smallvec` feature
+
+use crate::{EmptyError, NonEmtpyVec};
+use ::smallvec::{Array, SmallVec};
+use std::convert::TryFrom;
+
+impl<A: Array> TryFrom<SmallVec<A>> for NonEmtpyVec<A::Item> {
+    type Error = EmptyError<SmallVec<A>>;
+
+    #[inline]
+    fn try_from(v: SmallVec<A>) -> Result<Self, Self::Error> {
+        if v.is_empty() {
+            Err(EmptyError(v))
+        } else {
+            Ok(Self {
+                inner: v.into_vec(),
+            })
+        }
+    }
+}
+
+impl<A: Array> From<NonEmtpyVec<A::Item>> for SmallVec<A> {
+    #[inline]
+    fn from(v: NonEmtpyVec<A::Item>) -> Self {
+        SmallVec::from_vec(v.inner)
+    }
+}
@@ -0,0 +1,136 @@
+use crate::Drain;
+use std::fmt;
+use std::iter::FusedIterator;
+
+/// A splicing iterator for `NonEmptyVec<T>`
+///
+/// This is returned by [`NonEmptyVec::try_splice`](struct.NonEmtpyVec.html#method.try_splice).
+/// The replacement elements are inserted when this is dropped.
+/// If it is leaked, the `NonEmptyVec` is left in a valid (non-empty) state, possibly without the replacement
+pub struct Splice<'a, I: Iterator> {
+    drain: Drain<'a, I::Item>,
+    replace_with: I,
+    /// Where to insert the rest of `replace_with`
+    idx: usize,
+}
+
+impl<'a, I: Iterator> Splice<'a, I> {
+    pub(crate) fn new(drain: Drain<'a, I::Item>, replace_with: I, idx: usize) -> Self {
+        Self {
+            drain,
+            replace_with,
+            idx,
+        }
+    }
+}
+
+impl<I> fmt::Debug for Splice<'_, I>
+where
+    I: Iterator,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Splice").field(&self.drain).finish()
+    }
+}
+
+impl<I: Iterator> Iterator for Splice<'_, I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.drain.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl<I: Iterator> DoubleEndedIterator for Splice<'_, I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.drain.next_back()
+    }
+}
+
+impl<I: Iterator> ExactSizeIterator for Splice<'_, I> {}
+
+impl<I: Iterator> FusedIterator for Splice<'_, I> {}
+
+impl<I: Iterator> Drop for Splice<'_, I> {
+    fn drop(&mut self) {
+        let idx = self.idx;
+        let vec = self.drain.finish();
+        drop(vec.splice(idx..idx, &mut self.replace_with));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ne_vec;
+    use std::mem;
+    use std::panic::{self, AssertUnwindSafe};
+
+    #[test]
+    fn splice_range() {
+        let mut v = ne_vec![1, 2, 3, 4];
+        let removed: Vec<_> = v.try_splice(1..3, [7, 8, 9]).unwrap().collect();
+        assert_eq!(removed, [2, 3]);
+        assert_eq!(v, [1, 7, 8, 9, 4]);
+    }
+
+    #[test]
+    fn splice_shorter() {
+        let mut v = ne_vec![1, 2, 3, 4];
+        drop(v.try_splice(..3, [0]).unwrap());
+        assert_eq!(v, [0, 4]);
+    }
+
+    #[test]
+    fn splice_everything() {
+        let mut v = ne_vec![String::from("a"), "b".into()];
+        let removed: Vec<_> = v
+            .try_splice(.., vec!["c".into(), "d".into()])
+            .unwrap()
+            .collect();
+        assert_eq!(removed, ["a", "b"]);
+        assert_eq!(v, ["c", "d"]);
+    }
+
+    #[test]
+    fn splice_everything_with_nothing() {
+        let mut v = ne_vec![1, 2];
+        assert!(v.try_splice(.., []).is_err());
+        assert_eq!(v, [1, 2]);
+    }
+
+    #[test]
+    fn leak() {
+        let mut v = ne_vec![1, 2, 3];
+        let mut splice = v.try_splice(1.., [4]).unwrap();
+        assert_eq!(splice.next(), Some(2));
+        mem::forget(splice);
+        assert_eq!(v, [1]);
+    }
+
+    #[test]
+    fn leak_everything() {
+        let mut v = ne_vec![1, 2];
+        mem::forget(v.try_splice(.., [3, 4]).unwrap());
+        assert_eq!(v, [3]);
+    }
+
+    #[test]
+    fn panicking_replacement() {
+        let mut v = ne_vec![String::from("a"), "b".into(), "c".into()];
+        let res = panic::catch_unwind(AssertUnwindSafe(|| {
+            let replace_with = (0..).map(|i| if i < 1 { String::from("x") } else { panic!() });
+            drop(v.try_splice(..2, replace_with).unwrap());
+        }));
+        assert!(res.is_err());
+        assert!(!v.is_empty());
+        assert_eq!(v.last(), "c");
+    }
+}
@@ -0,0 +1,63 @@
+use crate::NonEmptySlice;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::slice;
+
+/// An iterator over the segments of a `NonEmptySlice<T>` separated (and terminated) by elements matching a predicate
+///
+/// This is returned by [`NonEmptySlice::split_inclusive`](struct.NonEmptySlice.html#method.split_inclusive)
+/// and [`NonEmptyVec::split_inclusive`](struct.NonEmtpyVec.html#method.split_inclusive).
+/// Like [`slice::SplitInclusive`](https://doc.rust-lang.org/std/slice/struct.SplitInclusive.html), but yields `NonEmptySlice`s.
+/// It always yields at least one segment
+pub struct SplitInclusive<'a, T, F: FnMut(&T) -> bool> {
+    inner: slice::SplitInclusive<'a, T, F>,
+}
+
+impl<'a, T, F: FnMut(&T) -> bool> SplitInclusive<'a, T, F> {
+    pub(crate) fn new(inner: slice::SplitInclusive<'a, T, F>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T: fmt::Debug, F: FnMut(&T) -> bool> fmt::Debug for SplitInclusive<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SplitInclusive").field(&self.inner).finish()
+    }
+}
+
+impl<T, F: FnMut(&T) -> bool + Clone> Clone for SplitInclusive<'_, T, F> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T, F: FnMut(&T) -> bool> Iterator for SplitInclusive<'a, T, F> {
+    type Item = &'a NonEmptySlice<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // this is ok since every segment contains at least its terminator or the last element
+        self.inner
+            .next()
+            .map(|s| unsafe { NonEmptySlice::from_slice_unchecked(s) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T, F: FnMut(&T) -> bool> DoubleEndedIterator for SplitInclusive<'_, T, F> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // this is ok since every segment contains at least its terminator or the last element
+        self.inner
+            .next_back()
+            .map(|s| unsafe { NonEmptySlice::from_slice_unchecked(s) })
+    }
+}
+
+impl<T, F: FnMut(&T) -> bool> FusedIterator for SplitInclusive<'_, T, F> {}
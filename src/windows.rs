@@ -0,0 +1,66 @@
+use crate::NonEmptySlice;
+use std::iter::FusedIterator;
+use std::slice;
+
+/// An iterator over overlapping windows of a `NonEmptySlice<T>`
+///
+/// This is returned by [`NonEmptySlice::windows`](struct.NonEmptySlice.html#method.windows)
+/// and [`NonEmptyVec::windows`](struct.NonEmtpyVec.html#method.windows).
+/// Like [`slice::Windows`](https://doc.rust-lang.org/std/slice/struct.Windows.html), but yields `NonEmptySlice`s
+#[derive(Debug)]
+pub struct Windows<'a, T> {
+    inner: slice::Windows<'a, T>,
+}
+
+impl<'a, T> Windows<'a, T> {
+    pub(crate) fn new(inner: slice::Windows<'a, T>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T> Clone for Windows<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = &'a NonEmptySlice<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // this is ok since the window size is never 0
+        self.inner
+            .next()
+            .map(|w| unsafe { NonEmptySlice::from_slice_unchecked(w) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // this is ok since the window size is never 0
+        self.inner
+            .nth(n)
+            .map(|w| unsafe { NonEmptySlice::from_slice_unchecked(w) })
+    }
+}
+
+impl<T> DoubleEndedIterator for Windows<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // this is ok since the window size is never 0
+        self.inner
+            .next_back()
+            .map(|w| unsafe { NonEmptySlice::from_slice_unchecked(w) })
+    }
+}
+
+impl<T> ExactSizeIterator for Windows<'_, T> {}
+
+impl<T> FusedIterator for Windows<'_, T> {}